@@ -0,0 +1,123 @@
+use std::convert::TryFrom;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use string32::String32;
+
+fn large_ascii() -> String32 {
+    let mut s = String32::with_capacity(1 << 20);
+    while s.len() < (1 << 20) - 64 {
+        s.push_str("the quick brown fox jumps over the lazy dog, again and again. ");
+    }
+    s
+}
+
+fn find_byte(c: &mut Criterion) {
+    let mut s = large_ascii();
+    s.push('\n');
+    c.bench_function("find_byte", |b| b.iter(|| black_box(&s).find_byte(b'\n')));
+    c.bench_function("str_find_char", |b| {
+        b.iter(|| black_box(s.as_str()).find('\n'))
+    });
+}
+
+fn count_byte(c: &mut Criterion) {
+    let s = large_ascii();
+    c.bench_function("count_byte", |b| b.iter(|| black_box(&s).count_byte(b' ')));
+    c.bench_function("bytes_filter_count", |b| {
+        b.iter(|| black_box(&s).bytes().filter(|&x| x == b' ').count())
+    });
+}
+
+fn reserve_amortized(c: &mut Criterion) {
+    c.bench_function("push_str_reserve_exact", |b| {
+        b.iter(|| {
+            let mut s = String32::new();
+            for _ in 0..10_000 {
+                s.reserve_exact(8);
+                s.push_str(black_box("abcdefgh"));
+            }
+            s
+        })
+    });
+    c.bench_function("push_str_reserve_amortized", |b| {
+        b.iter(|| {
+            let mut s = String32::new();
+            for _ in 0..10_000 {
+                s.reserve_amortized(8);
+                s.push_str(black_box("abcdefgh"));
+            }
+            s
+        })
+    });
+}
+
+fn is_ascii_fast(c: &mut Criterion) {
+    let s = large_ascii();
+    c.bench_function("is_ascii_fast", |b| {
+        b.iter(|| black_box(&s).is_ascii_fast())
+    });
+    c.bench_function("str_is_ascii", |b| {
+        b.iter(|| black_box(s.as_str()).is_ascii())
+    });
+}
+
+fn char_indices_count(c: &mut Criterion) {
+    let s = large_ascii();
+    c.bench_function("char_indices_count", |b| {
+        b.iter(|| black_box(&s).char_indices().count())
+    });
+    c.bench_function("str_char_indices_count", |b| {
+        b.iter(|| black_box(s.as_str()).char_indices().count())
+    });
+}
+
+fn collect_boxed(c: &mut Criterion) {
+    let pieces: Vec<Box<str>> = (0..10_000).map(|i| i.to_string().into()).collect();
+    c.bench_function("collect_boxed_str", |b| {
+        b.iter(|| black_box(pieces.clone()).into_iter().collect::<String32>())
+    });
+}
+
+fn collect_chars(c: &mut Criterion) {
+    c.bench_function("collect_ascii_chars", |b| {
+        b.iter(|| {
+            (0..1_000_000u32)
+                .map(|i| char::from(b'a' + (i % 26) as u8))
+                .collect::<String32>()
+        })
+    });
+}
+
+fn collect_string32(c: &mut Criterion) {
+    let pieces: Vec<String32> = (0..1000)
+        .map(|i| String32::try_from(i.to_string()).unwrap())
+        .collect();
+    c.bench_function("collect_string32", |b| {
+        b.iter(|| black_box(pieces.clone()).into_iter().collect::<String32>())
+    });
+}
+
+fn from_utf16(c: &mut Criterion) {
+    let s = large_ascii();
+    let v: Vec<u16> = s.as_str().replace(' ', "é😀").encode_utf16().collect();
+    c.bench_function("from_utf16", |b| {
+        b.iter(|| String32::from_utf16(black_box(&v)).unwrap())
+    });
+    c.bench_function("string_from_utf16", |b| {
+        b.iter(|| String::from_utf16(black_box(&v)).unwrap())
+    });
+}
+
+criterion_group!(
+    benches,
+    find_byte,
+    count_byte,
+    reserve_amortized,
+    is_ascii_fast,
+    char_indices_count,
+    collect_boxed,
+    collect_chars,
+    collect_string32,
+    from_utf16
+);
+criterion_main!(benches);
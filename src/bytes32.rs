@@ -0,0 +1,71 @@
+use std::convert::TryInto;
+use std::iter::FusedIterator;
+use std::str;
+
+use super::Str32;
+
+/// An iterator over the bytes of a [`Str32`], which reports its remaining length as a `u32`.
+///
+/// This struct is created by [`Str32::bytes32`].
+#[derive(Clone, Debug)]
+pub struct Bytes32<'a>(str::Bytes<'a>);
+
+impl<'a> Bytes32<'a> {
+    pub(crate) fn new(s: &'a Str32) -> Self {
+        Self(s.as_str().bytes())
+    }
+
+    /// Returns the number of bytes that have not yet been yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "abc".try_into().unwrap();
+    /// let mut iter = s.bytes32();
+    /// assert_eq!(3, iter.remaining());
+    /// iter.next();
+    /// assert_eq!(2, iter.remaining());
+    /// iter.next_back();
+    /// assert_eq!(1, iter.remaining());
+    /// ```
+    #[must_use]
+    pub fn remaining(&self) -> u32 {
+        self.0.len().try_into().unwrap()
+    }
+}
+
+impl Iterator for Bytes32<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.0.count()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<u8> {
+        self.0.nth(n)
+    }
+}
+
+impl DoubleEndedIterator for Bytes32<'_> {
+    fn next_back(&mut self) -> Option<u8> {
+        self.0.next_back()
+    }
+}
+
+impl ExactSizeIterator for Bytes32<'_> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl FusedIterator for Bytes32<'_> {}
@@ -0,0 +1,57 @@
+use std::cmp;
+use std::hash::{Hash, Hasher};
+
+use super::Str32;
+
+/// A wrapper around a `&Str32` which compares, orders, and hashes it ignoring ASCII case.
+///
+/// # Examples
+///
+/// ```
+/// # use string32::{CaseInsensitive, Str32};
+/// # use std::convert::TryInto;
+/// let mut v: Vec<CaseInsensitive> = ["B", "a", "C"]
+///     .iter()
+///     .map(|&s| CaseInsensitive(s.try_into().unwrap()))
+///     .collect();
+/// v.sort();
+/// let v: Vec<&str> = v.iter().map(|s| s.0.as_str()).collect();
+/// assert_eq!(vec!["a", "B", "C"], v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CaseInsensitive<'a>(pub &'a Str32);
+
+impl CaseInsensitive<'_> {
+    fn folded(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.bytes().map(|b| b.to_ascii_lowercase())
+    }
+}
+
+impl Eq for CaseInsensitive<'_> {}
+
+impl Hash for CaseInsensitive<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.folded() {
+            state.write_u8(b);
+        }
+        state.write_u8(0xff);
+    }
+}
+
+impl Ord for CaseInsensitive<'_> {
+    fn cmp(&self, rhs: &Self) -> cmp::Ordering {
+        self.folded().cmp(rhs.folded())
+    }
+}
+
+impl PartialEq for CaseInsensitive<'_> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(rhs.0)
+    }
+}
+
+impl PartialOrd for CaseInsensitive<'_> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
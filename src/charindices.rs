@@ -0,0 +1,64 @@
+use std::convert::TryInto;
+use std::iter::FusedIterator;
+use std::str;
+
+use super::Str32;
+
+/// An iterator over the `char`s of a [`Str32`], and their byte indices.
+///
+/// This struct is created by [`Str32::char_indices`].
+#[derive(Clone, Debug)]
+pub struct CharIndices<'a>(str::CharIndices<'a>);
+
+impl<'a> CharIndices<'a> {
+    pub(crate) fn new(s: &'a Str32) -> Self {
+        Self(s.as_str().char_indices())
+    }
+
+    /// Returns the remaining, unconsumed part of the underlying string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "abcd".try_into().unwrap();
+    /// let mut iter = s.char_indices();
+    /// iter.next();
+    /// iter.next();
+    /// assert_eq!("cd", iter.as_str32());
+    /// ```
+    #[must_use]
+    pub fn as_str32(&self) -> &'a Str32 {
+        self.0.as_str().try_into().unwrap()
+    }
+}
+
+impl Iterator for CharIndices<'_> {
+    type Item = (u32, char);
+
+    fn next(&mut self) -> Option<(u32, char)> {
+        self.0.next().map(|(i, c)| (i.try_into().unwrap(), c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    fn count(self) -> usize {
+        let rest = self.0.as_str();
+        if rest.is_ascii() {
+            rest.len()
+        } else {
+            rest.chars().count()
+        }
+    }
+}
+
+impl DoubleEndedIterator for CharIndices<'_> {
+    fn next_back(&mut self) -> Option<(u32, char)> {
+        self.0.next_back().map(|(i, c)| (i.try_into().unwrap(), c))
+    }
+}
+
+impl FusedIterator for CharIndices<'_> {}
@@ -0,0 +1,90 @@
+use std::ops;
+use std::sync::Arc;
+
+use super::{Str32, String32};
+
+/// A clone-on-write string, which is either a shared, immutable [`Arc<Str32>`] or an owned, mutable [`String32`].
+///
+/// Cloning a `CowStr32::Shared` only bumps a reference count, which makes it cheap to take snapshots of a document.
+/// The first mutation through [`CowStr32::make_mut`] copies the string out of the `Arc`.
+///
+/// # Examples
+///
+/// ```
+/// # use string32::{CowStr32, Str32};
+/// # use std::convert::TryInto;
+/// # use std::sync::Arc;
+/// let s: &Str32 = "draft".try_into().unwrap();
+/// let mut doc = CowStr32::from(Arc::<Str32>::from(s));
+/// let snapshot = doc.clone();
+/// doc.make_mut().push_str(" 2");
+/// assert_eq!("draft 2", &*doc);
+/// assert_eq!("draft", &*snapshot);
+/// ```
+#[derive(Clone, Debug)]
+pub enum CowStr32 {
+    /// A shared, immutable string.
+    Shared(Arc<Str32>),
+    /// An owned, mutable string.
+    Owned(String32),
+}
+
+impl CowStr32 {
+    /// Returns a mutable reference to the owned string, copying it out of the `Arc` first if it is shared.
+    pub fn make_mut(&mut self) -> &mut String32 {
+        if let Self::Shared(s) = self {
+            *self = Self::Owned(String32::from(&**s));
+        }
+        match self {
+            Self::Owned(s) => s,
+            Self::Shared(_) => unreachable!(),
+        }
+    }
+
+    /// Converts this `CowStr32` into a shared `Arc<Str32>`, copying the string if it is owned.
+    #[must_use]
+    pub fn into_shared(self) -> Arc<Str32> {
+        match self {
+            Self::Shared(s) => s,
+            Self::Owned(s) => s.into(),
+        }
+    }
+
+    /// Converts this `CowStr32` into an owned `String32`, copying the string if it is shared.
+    #[must_use]
+    pub fn into_owned(self) -> String32 {
+        match self {
+            Self::Shared(s) => String32::from(&*s),
+            Self::Owned(s) => s,
+        }
+    }
+}
+
+impl ops::Deref for CowStr32 {
+    type Target = Str32;
+
+    fn deref(&self) -> &Str32 {
+        match self {
+            Self::Shared(s) => s,
+            Self::Owned(s) => s,
+        }
+    }
+}
+
+impl AsRef<Str32> for CowStr32 {
+    fn as_ref(&self) -> &Str32 {
+        self
+    }
+}
+
+impl From<Arc<Str32>> for CowStr32 {
+    fn from(s: Arc<Str32>) -> Self {
+        Self::Shared(s)
+    }
+}
+
+impl From<String32> for CowStr32 {
+    fn from(s: String32) -> Self {
+        Self::Owned(s)
+    }
+}
@@ -0,0 +1,68 @@
+use std::convert::TryInto;
+use std::str;
+
+use super::Str32;
+
+/// A cursor over the `char`s of a [`Str32`], which tracks the byte offset of the current position.
+///
+/// This is intended as a building block for hand-written parsers.
+///
+/// # Examples
+///
+/// ```
+/// # use string32::{Cursor, Str32};
+/// # use std::convert::TryInto;
+/// let s: &Str32 = "ab".try_into().unwrap();
+/// let mut cursor = Cursor::new(s);
+/// assert_eq!(0, cursor.offset());
+/// assert_eq!(Some('a'), cursor.peek());
+/// assert_eq!(Some('a'), cursor.bump());
+/// assert_eq!(1, cursor.offset());
+/// assert_eq!("b", cursor.remaining());
+/// assert_eq!(Some('b'), cursor.bump());
+/// assert_eq!(2, cursor.offset());
+/// assert_eq!(None, cursor.bump());
+/// assert_eq!(2, cursor.offset());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cursor<'a> {
+    chars: str::Chars<'a>,
+    offset: u32,
+}
+
+impl<'a> Cursor<'a> {
+    /// Create a `Cursor` positioned at the start of `s`.
+    #[must_use]
+    pub fn new(s: &'a Str32) -> Self {
+        Self {
+            chars: s.as_str().chars(),
+            offset: 0,
+        }
+    }
+
+    /// Returns the next `char` without advancing the cursor.
+    #[must_use]
+    pub fn peek(&self) -> Option<char> {
+        self.chars.clone().next()
+    }
+
+    /// Advances the cursor past the next `char`, and returns it.
+    pub fn bump(&mut self) -> Option<char> {
+        let ch = self.chars.next()?;
+        let len: u32 = ch.len_utf8().try_into().unwrap();
+        self.offset += len;
+        Some(ch)
+    }
+
+    /// Returns the byte offset of the cursor from the start of the string.
+    #[must_use]
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the remaining, unconsumed part of the string.
+    #[must_use]
+    pub fn remaining(&self) -> &'a Str32 {
+        self.chars.as_str().try_into().unwrap()
+    }
+}
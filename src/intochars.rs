@@ -0,0 +1,51 @@
+use std::iter::FusedIterator;
+
+use usize_cast::IntoUsize;
+
+use super::String32;
+
+/// An owning iterator over the `char`s of a [`String32`].
+///
+/// This struct is created by [`String32::into_chars`].
+#[derive(Clone, Debug)]
+pub struct IntoChars {
+    s: String32,
+    front: u32,
+    back: u32,
+}
+
+impl IntoChars {
+    pub(crate) fn new(s: String32) -> Self {
+        let back = s.len();
+        Self { s, front: 0, back }
+    }
+
+    fn rest(&self) -> &str {
+        &self.s.as_str()[self.front.into_usize()..self.back.into_usize()]
+    }
+}
+
+impl Iterator for IntoChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.rest().chars().next()?;
+        self.front += ch.len_utf8() as u32;
+        Some(ch)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front).into_usize();
+        (len.div_ceil(4), Some(len))
+    }
+}
+
+impl DoubleEndedIterator for IntoChars {
+    fn next_back(&mut self) -> Option<char> {
+        let ch = self.rest().chars().next_back()?;
+        self.back -= ch.len_utf8() as u32;
+        Some(ch)
+    }
+}
+
+impl FusedIterator for IntoChars {}
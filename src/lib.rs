@@ -1,15 +1,41 @@
-#![allow(clippy::incorrect_partial_ord_impl_on_ord_type)]
+#![allow(clippy::non_canonical_partial_ord_impl)]
 //! A string that is indexed by `u32` instead of `usize`.
 //!
 //! On 64-bit platforms, `String32` only requires 16 bytes to store the pointer, length, and capacity. `String` by comparison requires 24 bytes, plus padding.
+use std::convert::TryFrom;
 use std::fmt;
+use std::io::{self, BufRead, Read};
 use std::mem::{align_of, size_of};
+use std::ops::{Bound, RangeBounds};
+use std::str;
 
+use usize_cast::IntoUsize;
+
+mod bytes32;
+mod caseinsensitive;
+mod charindices;
+mod cowstr32;
+mod cursor;
+mod intochars;
+mod pattern;
+mod prependbuilder;
 mod str32;
+mod str32builder;
+mod strext;
 mod string32;
 
 pub use crate::string32::String32;
+pub use bytes32::Bytes32;
+pub use caseinsensitive::CaseInsensitive;
+pub use charindices::CharIndices;
+pub use cowstr32::CowStr32;
+pub use cursor::Cursor;
+pub use intochars::IntoChars;
+pub use pattern::Pattern;
+pub use prependbuilder::PrependBuilder;
 pub use str32::Str32;
+pub use str32builder::Str32Builder;
+pub use strext::StrExt;
 
 /// The error returned when a `String` conversion to `String32` would require a buffer larger than `u32::MAX` bytes.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -38,6 +64,107 @@ impl fmt::Display for TryFromStrError {
     }
 }
 
+/// The error returned when a fallible edit of a `String32` cannot be performed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EditError {
+    /// The given byte index is past the end of the string.
+    OutOfBounds,
+    /// The given byte index is not a UTF-8 code point boundary.
+    NotCharBoundary,
+    /// The resulting string would require a buffer larger than `u32::MAX` bytes.
+    TooLarge,
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds => write!(f, "index is out of bounds"),
+            Self::NotCharBoundary => write!(f, "index is not a char boundary"),
+            Self::TooLarge => write!(f, "string too large for u32-indexed buffer"),
+        }
+    }
+}
+
+/// Read all bytes until a newline (the `0xA` byte) is reached, and append them to `buf`.
+///
+/// This mirrors [`BufRead::read_line`], which only accepts a [`String`]. The newline is included in `buf` if one is found.
+/// Returns the number of bytes read, which is zero once the reader reaches EOF.
+///
+/// # Errors
+///
+/// Returns any I/O error from the reader. Returns an error of kind [`io::ErrorKind::InvalidData`] if the line is not valid UTF-8, or if appending it would make `buf` longer than [`u32::MAX`] bytes.
+/// In both of these cases `buf` is left unchanged, but the bytes that were read have still been consumed from the reader.
+///
+/// # Examples
+///
+/// ```
+/// # use string32::String32;
+/// let mut reader: &[u8] = b"one\ntwo";
+/// let mut buf = String32::new();
+/// assert_eq!(4, string32::read_line(&mut reader, &mut buf).unwrap());
+/// assert_eq!("one\n", buf);
+/// assert_eq!(3, string32::read_line(&mut reader, &mut buf).unwrap());
+/// assert_eq!("one\ntwo", buf);
+/// assert_eq!(0, string32::read_line(&mut reader, &mut buf).unwrap());
+/// ```
+pub fn read_line<R>(reader: &mut R, buf: &mut String32) -> io::Result<u32>
+where
+    R: BufRead,
+{
+    let remaining = u32::MAX - buf.len();
+    let mut line = Vec::new();
+    reader
+        .by_ref()
+        .take(u64::from(remaining) + 1)
+        .read_until(b'\n', &mut line)?;
+    let n = u32::try_from(line.len())
+        .ok()
+        .filter(|&n| n <= remaining)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "string too large for u32-indexed buffer",
+            )
+        })?;
+    let line = str::from_utf8(&line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    buf.push_str(line);
+    Ok(n)
+}
+
+/// Write formatted text into a [`String32`], after reserving space for at least the given number of bytes.
+///
+/// This expands to a call to [`String32::write_fmt_reserved`].
+///
+/// # Examples
+///
+/// ```
+/// # use string32::{write32, String32};
+/// let mut s = String32::new();
+/// write32!(s, 64, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+/// assert_eq!("1 + 2 = 3", s);
+/// ```
+#[macro_export]
+macro_rules! write32 {
+    ($buf:expr, $hint:expr, $($arg:tt)*) => {
+        $buf.write_fmt_reserved($hint, format_args!($($arg)*))
+    };
+}
+
+/// Convert a range of `u32` indices into bounds on `usize` indices.
+pub(crate) fn usize_bounds<R>(range: &R) -> (Bound<usize>, Bound<usize>)
+where
+    R: RangeBounds<u32>,
+{
+    fn convert(bound: Bound<&u32>) -> Bound<usize> {
+        match bound {
+            Bound::Included(&i) => Bound::Included(i.into_usize()),
+            Bound::Excluded(&i) => Bound::Excluded(i.into_usize()),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
+    (convert(range.start_bound()), convert(range.end_bound()))
+}
+
 macro_rules! comptime_assert_eq {
     ($lhs:expr, $rhs:expr $(,)?) => {
         const _: [(); ($lhs == $rhs) as usize - 1] = [];
@@ -57,6 +184,24 @@ mod tests {
 
     const TEXT: &str = include_str!("lib.rs");
 
+    /// Returns `len` zero bytes for tests that need a buffer near the `u32::MAX` limit.
+    ///
+    /// The pages of a zeroed allocation are not touched until they are read, so this is cheap on hosts that allow memory overcommit.
+    #[cfg(target_pointer_width = "64")]
+    fn zeroed(len: usize) -> Vec<u8> {
+        vec![0; len]
+    }
+
+    /// Returns a `String32` of `len` NUL characters, see [`zeroed`].
+    #[cfg(target_pointer_width = "64")]
+    fn zeroed_string(len: u32) -> String32 {
+        let s = unsafe {
+            // safety: zero bytes are valid UTF-8
+            String::from_utf8_unchecked(zeroed(len.into_usize()))
+        };
+        String32::try_from(s).unwrap()
+    }
+
     #[test]
     fn test_simple() {
         let s1 = String::from(TEXT);
@@ -86,6 +231,457 @@ mod tests {
         assert!(!other.is_empty());
     }
 
+    #[test]
+    fn test_boxed_str32_into() {
+        fn boxed(s: &str) -> Box<Str32> {
+            let ptr = Box::into_raw(Box::<str>::from(s)) as *mut Str32;
+            unsafe { Box::from_raw(ptr) }
+        }
+
+        assert_eq!("héllo", boxed("héllo").into_string());
+        assert_eq!("héllo", &*boxed("héllo").into_boxed_str());
+        assert_eq!("héllo".as_bytes(), &*boxed("héllo").into_boxed_bytes());
+        assert_eq!("héllo", boxed("héllo").into_string32());
+    }
+
+    #[test]
+    fn test_reserve_amortized() {
+        fn count_reallocs(reserve: fn(&mut String32, u32)) -> u32 {
+            let mut s = String32::new();
+            let mut reallocs = 0;
+            for _ in 0..1000 {
+                let cap = s.capacity();
+                reserve(&mut s, 3);
+                if s.capacity() != cap {
+                    reallocs += 1;
+                }
+                s.push_str("abc");
+            }
+            reallocs
+        }
+
+        let exact = count_reallocs(String32::reserve_exact);
+        let amortized = count_reallocs(String32::reserve_amortized);
+        assert!(amortized <= 12);
+        assert!(amortized * 10 < exact);
+    }
+
+    #[test]
+    fn test_from_utf16() {
+        let mut v: Vec<u16> = TEXT.replace(' ', "é𝄞").encode_utf16().collect();
+        assert_eq!(
+            String::from_utf16(&v).unwrap(),
+            String32::from_utf16(&v).unwrap()
+        );
+        v.insert(v.len() / 2, 0xD834);
+        assert!(String32::from_utf16(&v).is_err());
+        assert_eq!(String::from_utf16_lossy(&v), String32::from_utf16_lossy(&v));
+    }
+
+    #[test]
+    fn test_reserve_exact() {
+        let mut s = String32::new();
+        for additional in 1..100 {
+            s.push_str("abc");
+            s.shrink_to_fit();
+            let len = s.len();
+            s.reserve_exact(additional);
+            assert_eq!(s.capacity(), len + additional);
+        }
+    }
+
+    #[test]
+    fn test_const_len() {
+        const S: &Str32 = Str32::from_str_unchecked("aé𝄞");
+        const LEN: u32 = S.len();
+        const IS_EMPTY: bool = S.is_empty();
+        comptime_assert_eq!(LEN, 7);
+        comptime_assert_eq!(IS_EMPTY, false);
+        assert_eq!(7, LEN);
+    }
+
+    #[test]
+    fn test_estimate_capacity() {
+        for chars in [0, 1, 7, 100, 12345] {
+            let mut prev = String32::estimate_capacity(chars, 0.0);
+            assert!(prev >= chars * 2);
+            assert!(prev <= chars * 3);
+            for i in 1..=10 {
+                let est = String32::estimate_capacity(chars, i as f32 / 10.0);
+                assert!(est >= chars);
+                assert!(est <= prev);
+                prev = est;
+            }
+            assert_eq!(chars, prev);
+        }
+        assert_eq!(25, String32::estimate_capacity(10, f32::NAN));
+        assert_eq!(10, String32::estimate_capacity(10, 2.0));
+        assert_eq!(25, String32::estimate_capacity(10, -1.0));
+    }
+
+    #[test]
+    fn test_try_from_str32() {
+        fn convert<'a, T: TryFrom<&'a Str32>>(s: &'a Str32) -> Option<T> {
+            T::try_from(s).ok()
+        }
+
+        let s = String32::try_from(TEXT).unwrap();
+        let owned: String32 = convert(&s).unwrap();
+        assert_eq!(s, owned);
+    }
+
+    #[test]
+    fn test_try_from_vec_char() {
+        let v = vec!['a', 'é', '𝄞', '\n'];
+        let s = String32::try_from(v).unwrap();
+        assert_eq!("aé𝄞\n", s);
+        assert_eq!(s.len(), s.capacity());
+
+        let s = String32::try_from(Vec::<char>::new()).unwrap();
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_try_from_char_slice() {
+        let s = String32::try_from(&['a', 'b', 'c'][..]).unwrap();
+        assert_eq!("abc", s);
+
+        let chars = ['€', 'x'];
+        let s = String32::try_from(&chars[..]).unwrap();
+        assert_eq!("€x", s);
+        assert_eq!(s.len(), s.capacity());
+    }
+
+    #[test]
+    fn test_is_ascii_fast() {
+        let mut state = 0x2545_F491_u32;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+
+        for _ in 0..200 {
+            let len = next() % 100;
+            let s: String32 = (0..len)
+                .map(|_| match next() % 64 {
+                    0 => 'é',
+                    n => char::from(b' ' + n as u8),
+                })
+                .collect();
+            for start in 0..s.len() {
+                if let Some(sub) = s.as_str().get(start.into_usize()..) {
+                    let sub = <&Str32>::try_from(sub).unwrap();
+                    assert_eq!(sub.as_str().is_ascii(), sub.is_ascii_fast());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fmt_flags() {
+        let s1 = String32::try_from("abcde").unwrap();
+        let s2: &Str32 = &s1;
+        assert_eq!("     abcde", format!("{:>10}", s1));
+        assert_eq!("     abcde", format!("{:>10}", s2));
+        assert_eq!("abcde*****", format!("{:*<10}", s2));
+        assert_eq!("abc", format!("{:.3}", s1));
+        assert_eq!("abc", format!("{:.3}", s2));
+        assert_eq!("  abc", format!("{:>5.3}", s2));
+    }
+
+    #[test]
+    fn test_contains_ascii() {
+        let s = String32::try_from(TEXT).unwrap();
+        for b in 0..0x80 {
+            assert_eq!(s.as_str().contains(char::from(b)), s.contains_ascii(b));
+        }
+    }
+
+    #[test]
+    fn test_from_iter_char() {
+        let s1: String = TEXT.chars().chain("aé€😀".chars()).collect();
+        let s2: String32 = TEXT.chars().chain("aé€😀".chars()).collect();
+        assert_eq!(s1, s2);
+    }
+
+    #[test]
+    fn test_from_iter_string32() {
+        let lines = || TEXT.lines().map(|line| String32::try_from(line).unwrap());
+        let s: String32 = lines().collect();
+        assert_eq!(TEXT.lines().collect::<String>(), s);
+        assert_eq!("", lines().take(0).collect::<String32>());
+    }
+
+    #[test]
+    fn test_cmp_ref_string32() {
+        fn eq<A, B>(a: &A, b: &B) -> bool
+        where
+            A: PartialEq<B> + PartialOrd<B> + ?Sized,
+            B: ?Sized,
+        {
+            a == b && a.partial_cmp(b) == Some(std::cmp::Ordering::Equal)
+        }
+
+        let s = String32::try_from("abc").unwrap();
+        let s32 = <&Str32>::try_from("abc").unwrap();
+        assert!(eq(&&s, "abc"));
+        assert!(eq("abc", &&s));
+        assert!(eq(&&s, s32));
+        assert!(eq(s32, &&s));
+        assert!(eq(&&s, &String::from("abc")));
+        assert!(eq(&String::from("abc"), &&s));
+        assert!(eq(&&s, &std::borrow::Cow::Borrowed(s32)));
+        assert!(eq(&&s, &std::borrow::Cow::Borrowed("abc")));
+        assert!(eq(&&s, &Box::<str>::from("abc")));
+        assert!(eq(&&s, &Box::<Str32>::from(s32)));
+        assert!(&s < "abd");
+    }
+
+    #[test]
+    fn test_cmp_bytes() {
+        let s = String32::try_from(TEXT).unwrap();
+        let v = s.clone().into_bytes();
+        assert_eq!(s, v);
+        assert_eq!(s, &v);
+        assert_eq!(v, s);
+        assert_eq!(*s, v);
+        assert!(s < b"~".to_vec());
+        assert!(b"".to_vec() < *s);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    #[should_panic(expected = "string too large for u32-indexed buffer")]
+    fn test_push_overflow() {
+        let mut s = zeroed_string(u32::MAX);
+        s.push('a');
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    #[should_panic(expected = "string too large for u32-indexed buffer")]
+    fn test_reserve_amortized_overflow() {
+        let mut s = zeroed_string(u32::MAX);
+        s.reserve_amortized(1);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    #[should_panic(expected = "string too large for u32-indexed buffer")]
+    fn test_push_str32_overflow() {
+        let mut s = zeroed_string(u32::MAX);
+        s += <&Str32>::try_from("a").unwrap();
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    #[should_panic(expected = "string too large for u32-indexed buffer")]
+    fn test_from_utf8_overflow() {
+        let _ = String32::from_utf8(zeroed(u32::MAX.into_usize() + 1));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_try_insert_str_too_large() {
+        let mut s = zeroed_string(u32::MAX - 1);
+        assert_eq!(Err(EditError::TooLarge), s.try_insert_str(0, "ab"));
+        assert_eq!(u32::MAX - 1, s.len());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_try_as_string_overflow() {
+        let mut s = zeroed_string(u32::MAX);
+        match s.try_as_string(|s| s.push('a')) {
+            Ok(()) => panic!("expected an oversized string"),
+            Err(e) => assert_eq!(u32::MAX.into_usize() + 1, e.into_inner().len()),
+        }
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut s = String32::with_capacity(64);
+        s.push_str("correct horse battery staple");
+        let cap = s.capacity();
+        s.zeroize();
+        assert!(s.is_empty());
+        assert_eq!(cap, s.capacity());
+        let bytes = unsafe { std::slice::from_raw_parts(s.as_ptr(), cap.into_usize()) };
+        assert!(bytes.iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "byte index 7 is out of bounds")]
+    fn test_insert_out_of_bounds() {
+        String32::try_from("abc").unwrap().insert(7, 'x');
+    }
+
+    #[test]
+    #[should_panic(expected = "byte index 2 is not a char boundary")]
+    fn test_insert_str_not_char_boundary() {
+        String32::try_from("aé").unwrap().insert_str(2, "x");
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot remove a char at byte index 3")]
+    fn test_remove_out_of_bounds() {
+        String32::try_from("abc").unwrap().remove(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "byte index 2 is not a char boundary")]
+    fn test_remove_not_char_boundary() {
+        String32::try_from("aé").unwrap().remove(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "byte index 2 is not a char boundary")]
+    fn test_truncate_not_char_boundary() {
+        String32::try_from("aé").unwrap().truncate(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "byte index 4 is out of bounds")]
+    fn test_split_off_out_of_bounds() {
+        let _ = String32::try_from("abc").unwrap().split_off(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "byte index 1 is not a char boundary")]
+    fn test_split_off_front_not_char_boundary() {
+        let _ = String32::try_from("éa").unwrap().split_off_front(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "string too large for u32-indexed buffer")]
+    fn test_repeat_into_overflow() {
+        let mut s = String32::new();
+        <&Str32>::try_from("ab")
+            .unwrap()
+            .repeat_into(u32::MAX, &mut s);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    #[should_panic(expected = "string too large for u32-indexed buffer")]
+    fn test_repeat_into_nonempty_overflow() {
+        let mut s = zeroed_string(u32::MAX - 1);
+        <&Str32>::try_from("ab").unwrap().repeat_into(1, &mut s);
+    }
+
+    #[test]
+    fn test_repeat_into_empty() {
+        let mut s = String32::try_from("x").unwrap();
+        <&Str32>::try_from("")
+            .unwrap()
+            .repeat_into(u32::MAX, &mut s);
+        <&Str32>::try_from("ab").unwrap().repeat_into(0, &mut s);
+        assert_eq!("x", s);
+    }
+
+    #[test]
+    #[should_panic(expected = "string too large for u32-indexed buffer")]
+    fn test_reserve_chars_width_overflow() {
+        String32::new().reserve_chars_width(u32::MAX, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "`to` must be an ASCII byte")]
+    fn test_replace_ascii_non_ascii() {
+        let mut s = String32::try_from("abc").unwrap();
+        s.replace_ascii_in_place(b'a', 0xC3);
+    }
+
+    #[test]
+    fn test_boxed_str32_key() {
+        use std::collections::HashMap;
+
+        let a = <&Str32>::try_from("a").unwrap();
+        let b = <&Str32>::try_from("b").unwrap();
+        let mut map: HashMap<Box<Str32>, u32> = HashMap::new();
+        map.insert(Box::from(a), 1);
+        map.insert(Box::from(String32::try_from("b").unwrap()), 2);
+        assert_eq!(Some(&1), map.get(a));
+        assert_eq!(Some(&2), map.get(b));
+        assert_eq!(None, map.get(<&Str32>::try_from("c").unwrap()));
+    }
+
+    #[test]
+    fn test_read_line() {
+        let mut reader: &[u8] = b"first\r\nsecond\nthird";
+        let mut buf = String32::new();
+        assert_eq!(7, read_line(&mut reader, &mut buf).unwrap());
+        assert_eq!("first\r\n", buf);
+        buf.clear();
+        assert_eq!(7, read_line(&mut reader, &mut buf).unwrap());
+        assert_eq!("second\n", buf);
+        assert_eq!(5, read_line(&mut reader, &mut buf).unwrap());
+        assert_eq!("second\nthird", buf);
+        assert_eq!(0, read_line(&mut reader, &mut buf).unwrap());
+
+        let mut reader: &[u8] = b"ok\xFF\nrest\n";
+        let err = read_line(&mut reader, &mut buf).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        assert_eq!("second\nthird", buf);
+        assert_eq!(5, read_line(&mut reader, &mut buf).unwrap());
+    }
+
+    #[test]
+    fn test_write_fmt_reserved() {
+        use std::fmt::Write;
+
+        let mut plain = String32::new();
+        for i in 0..1000 {
+            write!(plain, "{{\"id\": {}, \"name\": \"item{:04}\"}},", i, i).unwrap();
+        }
+
+        let mut reserved = String32::new();
+        for i in 0..1000 {
+            crate::write32!(
+                reserved,
+                32,
+                "{{\"id\": {}, \"name\": \"item{:04}\"}},",
+                i,
+                i
+            )
+            .unwrap();
+        }
+
+        let mut hinted = String32::new();
+        hinted
+            .write_fmt_reserved(plain.len(), format_args!("{}", plain))
+            .unwrap();
+        let cap = hinted.capacity();
+
+        assert_eq!(plain, reserved);
+        assert_eq!(plain, hinted);
+        assert_eq!(cap, hinted.len());
+    }
+
+    #[test]
+    fn test_cow_str32_snapshot() {
+        use std::sync::Arc;
+
+        let mut doc = CowStr32::from(String32::try_from("v1").unwrap());
+        let snapshot = CowStr32::from(doc.clone().into_shared());
+        let snapshot2 = snapshot.clone();
+        if let (CowStr32::Shared(a), CowStr32::Shared(b)) = (&snapshot, &snapshot2) {
+            assert!(Arc::ptr_eq(a, b));
+        } else {
+            panic!("expected shared snapshots");
+        }
+
+        doc.make_mut().push_str(", edited");
+        let mut fork = snapshot2;
+        fork.make_mut().insert_str(0, "fork of ");
+        assert_eq!("v1, edited", &*doc);
+        assert_eq!("v1", &*snapshot);
+        assert_eq!("fork of v1", fork.into_owned());
+    }
+
     #[test]
     fn test_hash() {
         use std::collections::hash_map::DefaultHasher;
@@ -101,6 +697,8 @@ mod tests {
         <String32 as Hash>::hash(&s2, &mut hash2);
         <str as Hash>::hash(&s1, &mut hash1);
         <Str32 as Hash>::hash(&s2, &mut hash2);
+        <Box<str> as Hash>::hash(&Box::from(&*s1), &mut hash1);
+        <Box<Str32> as Hash>::hash(&Box::from(&*s2), &mut hash2);
 
         assert_eq!(hash1.finish(), hash2.finish());
     }
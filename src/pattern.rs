@@ -0,0 +1,104 @@
+use super::{Str32, String32};
+
+/// A pattern that can be searched for in a [`Str32`].
+///
+/// This is a stable stand-in for the unstable `std::str::pattern::Pattern` trait. It is implemented for `char`, `&str`, `&String`, `&Str32`, `&String32`, `&[char]`, `[char; N]`, and closures of type `FnMut(char) -> bool`, all of which match the same way as they do for [`str`] methods.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Pattern: private::Sealed {}
+
+pub(crate) mod private {
+    /// The methods of [`Pattern`](super::Pattern), which are kept out of the public API.
+    pub trait Sealed {
+        /// Returns the byte range of the first match in `haystack` that starts at or after byte index `start`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `start` is not a UTF-8 code point boundary of `haystack`.
+        fn find_in(&mut self, haystack: &str, start: usize) -> Option<(usize, usize)>;
+
+        /// Returns the length in bytes of a match at the start of `haystack`, if there is one.
+        fn prefix_len_in(&mut self, haystack: &str) -> Option<usize>;
+
+        /// Returns the length in bytes of a match at the end of `haystack`, if there is one.
+        fn suffix_len_in(&mut self, haystack: &str) -> Option<usize>;
+    }
+}
+
+macro_rules! impl_pattern {
+    ([$($generics:tt)*] $ty:ty, |$pat:ident| $as_std:expr) => {
+        impl<$($generics)*> Pattern for $ty {}
+
+        impl<$($generics)*> private::Sealed for $ty {
+            fn find_in(&mut self, haystack: &str, start: usize) -> Option<(usize, usize)> {
+                let $pat = self;
+                haystack[start..]
+                    .match_indices($as_std)
+                    .next()
+                    .map(|(i, m)| (start + i, start + i + m.len()))
+            }
+
+            fn prefix_len_in(&mut self, haystack: &str) -> Option<usize> {
+                let $pat = self;
+                haystack
+                    .strip_prefix($as_std)
+                    .map(|rest| haystack.len() - rest.len())
+            }
+
+            fn suffix_len_in(&mut self, haystack: &str) -> Option<usize> {
+                let $pat = self;
+                haystack
+                    .strip_suffix($as_std)
+                    .map(|rest| haystack.len() - rest.len())
+            }
+        }
+    };
+}
+
+impl_pattern!([] char, |pat| *pat);
+impl_pattern!(['b] &'b str, |pat| *pat);
+impl_pattern!(['b] &'b String, |pat| pat.as_str());
+impl_pattern!(['b] &'b Str32, |pat| pat.as_str());
+impl_pattern!(['b] &'b String32, |pat| pat.as_str());
+impl_pattern!(['b] &'b [char], |pat| *pat);
+impl_pattern!([const N: usize] [char; N], |pat| *pat);
+impl_pattern!([F: FnMut(char) -> bool] F, |pat| &mut *pat);
+
+/// An iterator over the byte ranges of the non-overlapping matches of a [`Pattern`].
+pub(crate) struct MatchRanges<'a, P> {
+    haystack: &'a str,
+    pat: P,
+    pos: Option<usize>,
+}
+
+impl<'a, P: Pattern> MatchRanges<'a, P> {
+    pub(crate) fn new(haystack: &'a str, pat: P) -> Self {
+        Self {
+            haystack,
+            pat,
+            pos: Some(0),
+        }
+    }
+}
+
+impl<P: Pattern> Iterator for MatchRanges<'_, P> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let pos = self.pos?;
+        let (start, end) = self.pat.find_in(self.haystack, pos).or_else(|| {
+            self.pos = None;
+            None
+        })?;
+        self.pos = if start == end {
+            // step over the next char, so that empty matches still make progress
+            self.haystack[end..]
+                .chars()
+                .next()
+                .map(|c| end + c.len_utf8())
+        } else {
+            Some(end)
+        };
+        Some((start, end))
+    }
+}
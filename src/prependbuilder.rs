@@ -0,0 +1,54 @@
+use std::convert::TryInto;
+
+use super::String32;
+
+/// A builder for a [`String32`] that is assembled by repeatedly prepending string slices.
+///
+/// Calling [`String32::prepend_str`] in a loop moves the existing contents on every call, which is quadratic in the final length.
+/// A `PrependBuilder` instead records each piece, and assembles them all in a single pass when [`PrependBuilder::build`] is called.
+///
+/// # Examples
+///
+/// ```
+/// # use string32::PrependBuilder;
+/// let mut b = PrependBuilder::new();
+/// b.prepend("c");
+/// b.prepend("b/");
+/// b.prepend("a/");
+/// assert_eq!("a/b/c", b.build());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PrependBuilder {
+    buf: String,
+    starts: Vec<usize>,
+}
+
+impl PrependBuilder {
+    /// Create an empty `PrependBuilder`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prepend a string slice to the front of the string being built.
+    pub fn prepend(&mut self, s: &str) {
+        self.starts.push(self.buf.len());
+        self.buf.push_str(s);
+    }
+
+    /// Assemble the prepended pieces into a `String32`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would require more than [`u32::MAX`] bytes.
+    #[must_use]
+    pub fn build(self) -> String32 {
+        let mut ret = String::with_capacity(self.buf.len());
+        let mut end = self.buf.len();
+        for &start in self.starts.iter().rev() {
+            ret.push_str(&self.buf[start..end]);
+            end = start;
+        }
+        ret.try_into().unwrap()
+    }
+}
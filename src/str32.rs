@@ -2,9 +2,18 @@ use std::cmp;
 use std::convert::{TryFrom, TryInto};
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::RangeBounds;
+use std::sync::Arc;
 
 use usize_cast::IntoUsize;
 
+use super::pattern::MatchRanges;
+use super::usize_bounds;
+use super::Bytes32;
+use super::CharIndices;
+use super::EditError;
+use super::Pattern;
 use super::String32;
 use super::TryFromStrError;
 
@@ -52,6 +61,36 @@ impl Str32 {
         self.0.as_bytes()
     }
 
+    /// Splits the bytes of the `Str32` into `N`-byte arrays, and a remainder of fewer than `N` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "0123456789abcdef0123456789abcdefxyz".try_into().unwrap();
+    /// let (chunks, rest) = s.as_byte_chunks::<16>();
+    /// assert_eq!(2, chunks.len());
+    /// assert_eq!(b"0123456789abcdef", &chunks[1]);
+    /// assert_eq!(b"xyz", rest);
+    /// ```
+    #[must_use]
+    pub fn as_byte_chunks<const N: usize>(&self) -> (&[[u8; N]], &[u8]) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let bytes = self.as_bytes();
+        let (head, tail) = bytes.split_at(bytes.len() / N * N);
+        let ptr = head.as_ptr() as *const [u8; N];
+        let chunks = unsafe {
+            // safety: `head` holds exactly `head.len() / N` arrays of `N` bytes, and `[u8; N]` has an alignment of 1
+            std::slice::from_raw_parts(ptr, head.len() / N)
+        };
+        (chunks, tail)
+    }
+
     /// Converts the `Str32` to a byte slice.
     ///
     /// # Examples
@@ -77,6 +116,234 @@ impl Str32 {
         self.0.bytes()
     }
 
+    /// Returns an iterator over the bytes of the string slice, which can report its remaining length as a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "ab".try_into().unwrap();
+    /// let mut iter = s.bytes32();
+    /// assert_eq!(Some(b'a'), iter.next());
+    /// assert_eq!(1, iter.remaining());
+    /// ```
+    pub fn bytes32(&self) -> Bytes32<'_> {
+        Bytes32::new(self)
+    }
+
+    /// Returns whether the bytes of the `Str32` start with the given bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "PK\x03\x04rest".try_into().unwrap();
+    /// assert!(s.has_prefix_bytes(b"PK"));
+    /// assert!(s.has_prefix_bytes(b"PK\x03\x04"));
+    /// assert!(!s.has_prefix_bytes(b"\x7FELF"));
+    /// ```
+    #[must_use]
+    pub fn has_prefix_bytes(&self, prefix: &[u8]) -> bool {
+        self.as_bytes().starts_with(prefix)
+    }
+
+    /// Returns whether the bytes of the `Str32` end with the given bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "data\x00\x00".try_into().unwrap();
+    /// assert!(s.has_suffix_bytes(b"\x00\x00"));
+    /// assert!(!s.has_suffix_bytes(b"data"));
+    /// ```
+    #[must_use]
+    pub fn has_suffix_bytes(&self, suffix: &[u8]) -> bool {
+        self.as_bytes().ends_with(suffix)
+    }
+
+    /// Returns an iterator over all overlapping windows of `size` bytes.
+    ///
+    /// The iterator is empty if `size` is zero or greater than the length of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "abc".try_into().unwrap();
+    /// let v: Vec<&[u8]> = s.byte_windows(2).collect();
+    /// assert_eq!(vec![b"ab", b"bc"], v);
+    /// assert_eq!(0, s.byte_windows(0).count());
+    /// assert_eq!(0, s.byte_windows(4).count());
+    /// ```
+    pub fn byte_windows(&self, size: u32) -> std::slice::Windows<'_, u8> {
+        let bytes = if size == 0 { &[] } else { self.as_bytes() };
+        bytes.windows(cmp::max(size, 1).into_usize())
+    }
+
+    /// Returns an iterator over the bytes of the string slice, in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "ab".try_into().unwrap();
+    /// let mut iter = s.rbytes();
+    /// assert_eq!(Some(b'b'), iter.next());
+    /// assert_eq!(Some(b'a'), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    pub fn rbytes(&self) -> std::iter::Rev<std::str::Bytes<'_>> {
+        self.0.bytes().rev()
+    }
+
+    /// Returns the byte index of the first occurrence of the byte `b`, or `None` if it does not occur.
+    ///
+    /// With the `memchr` feature enabled, this is backed by the [`memchr`](https://docs.rs/memchr) crate.
+    ///
+    /// `b` should be an ASCII byte. Any other byte can only occur inside a multi-byte UTF-8 sequence, so the returned index would not be a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "abc\ndef\n".try_into().unwrap();
+    /// assert_eq!(Some(3), s.find_byte(b'\n'));
+    /// assert_eq!(None, s.find_byte(b'x'));
+    /// ```
+    #[must_use]
+    pub fn find_byte(&self, b: u8) -> Option<u32> {
+        #[cfg(feature = "memchr")]
+        let idx = memchr::memchr(b, self.as_bytes());
+        #[cfg(not(feature = "memchr"))]
+        let idx = self.as_bytes().iter().position(|&x| x == b);
+        idx.map(|i| i.try_into().unwrap())
+    }
+
+    /// Returns the byte index of the last occurrence of the byte `b`, or `None` if it does not occur.
+    ///
+    /// With the `memchr` feature enabled, this is backed by the [`memchr`](https://docs.rs/memchr) crate.
+    ///
+    /// `b` should be an ASCII byte, see [`Str32::find_byte`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "a/b/c".try_into().unwrap();
+    /// assert_eq!(Some(3), s.rfind_byte(b'/'));
+    /// assert_eq!(None, s.rfind_byte(b'\\'));
+    /// ```
+    #[must_use]
+    pub fn rfind_byte(&self, b: u8) -> Option<u32> {
+        #[cfg(feature = "memchr")]
+        let idx = memchr::memrchr(b, self.as_bytes());
+        #[cfg(not(feature = "memchr"))]
+        let idx = self.as_bytes().iter().rposition(|&x| x == b);
+        idx.map(|i| i.try_into().unwrap())
+    }
+
+    /// Returns the number of occurrences of the byte `b`.
+    ///
+    /// With the `memchr` feature enabled, this is backed by the [`memchr`](https://docs.rs/memchr) crate.
+    /// Otherwise it compares a machine word of bytes at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "one\ntwo\nthree\n".try_into().unwrap();
+    /// assert_eq!(3, s.count_byte(b'\n'));
+    /// assert_eq!(0, s.count_byte(b'x'));
+    /// ```
+    #[must_use]
+    pub fn count_byte(&self, b: u8) -> u32 {
+        #[cfg(feature = "memchr")]
+        let n = memchr::memchr_iter(b, self.as_bytes()).count();
+        #[cfg(not(feature = "memchr"))]
+        let n = {
+            const WORD: usize = mem::size_of::<usize>();
+            const LOW_BITS: usize = usize::MAX / 0xFF * 0x7F;
+            const HIGH_BITS: usize = usize::MAX / 0xFF * 0x80;
+
+            let needle = usize::MAX / 0xFF * usize::from(b);
+            let mut words = self.as_bytes().chunks_exact(WORD);
+            let mut n = 0;
+            for w in &mut words {
+                // each byte of `x` is zero exactly where `w` matches `b`
+                let x = usize::from_ne_bytes(w.try_into().unwrap()) ^ needle;
+                // the high bit of each byte is set iff that byte of `x` is nonzero, without carries between bytes
+                let nonzero = ((x & LOW_BITS) + LOW_BITS) | x;
+                n += (!nonzero & HIGH_BITS).count_ones().into_usize();
+            }
+            n + words.remainder().iter().filter(|&&x| x == b).count()
+        };
+        n.try_into().unwrap()
+    }
+
+    /// Returns whether the byte `b` occurs in this `Str32`.
+    ///
+    /// For an ASCII byte, this agrees with `contains(char::from(b))` but is a plain byte scan.
+    /// `b` should be an ASCII byte, see [`Str32::find_byte`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "a,b;c".try_into().unwrap();
+    /// assert!(s.contains_ascii(b','));
+    /// assert!(!s.contains_ascii(b'\t'));
+    /// ```
+    #[must_use]
+    pub fn contains_ascii(&self, b: u8) -> bool {
+        self.find_byte(b).is_some()
+    }
+
+    /// Returns `true` if this string contains at least one of the given substrings.
+    ///
+    /// Returns `false` if `pats` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "hello world".try_into().unwrap();
+    /// assert!(s.contains_any(&["xyz", "world"]));
+    /// assert!(!s.contains_any(&["xyz", "abc"]));
+    /// ```
+    #[must_use]
+    pub fn contains_any(&self, pats: &[&str]) -> bool {
+        pats.iter().any(|pat| self.0.contains(pat))
+    }
+
+    /// Returns `true` if this string contains every one of the given substrings.
+    ///
+    /// Returns `true` if `pats` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "hello world".try_into().unwrap();
+    /// assert!(s.contains_all(&["hello", "world"]));
+    /// assert!(!s.contains_all(&["hello", "xyz"]));
+    /// ```
+    #[must_use]
+    pub fn contains_all(&self, pats: &[&str]) -> bool {
+        pats.iter().all(|pat| self.0.contains(pat))
+    }
+
     /// Converts the `Str32` to a raw pointer.
     #[must_use]
     pub const fn as_ptr(&self) -> *const u8 {
@@ -92,131 +359,939 @@ impl Str32 {
         self.0.as_mut_ptr()
     }
 
+    /// Converts a `&str` into a `&Str32` in a `const` context.
+    ///
+    /// Unlike the [`TryFrom`] conversion, this is a `const fn`, so it can be used to build `&Str32` constants and statics from string literals.
+    /// The length is still checked, but with an assertion rather than a `Result`, so in a `const` context an oversized string is a compile-time error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided string slice occupies more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// const GREETINGS: [&Str32; 2] = [
+    ///     Str32::from_str_unchecked("hello"),
+    ///     Str32::from_str_unchecked("world"),
+    /// ];
+    /// assert_eq!("world", GREETINGS[1]);
+    /// ```
+    #[must_use]
+    pub const fn from_str_unchecked(s: &str) -> &Self {
+        assert!(
+            s.len() as u64 <= u32::MAX as u64,
+            "string too large for u32-indexed buffer"
+        );
+        let ptr = s as *const str as *const Self;
+        unsafe {
+            // safety: relies on `&Str32` and `&str` having the same layout
+            &*ptr
+        }
+    }
+
     /// Converts a `&mut str` into a `&mut Str32`.
     ///
     /// # Panics
     ///
     /// Panics if the provided string slice occupies more than [`u32::MAX`] bytes.
     #[must_use]
-    pub fn from_mut_str(s: &mut str) -> &mut Self {
-        s.try_into().unwrap()
+    pub fn from_mut_str(s: &mut str) -> &mut Self {
+        s.try_into().unwrap()
+    }
+
+    /// Returns the length of the `Str32` in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "test".try_into().unwrap();
+    /// assert_eq!(4, s.len());
+    ///
+    /// const LEN: u32 = Str32::from_str_unchecked("test").len();
+    /// assert_eq!(4, LEN);
+    /// ```
+    #[must_use]
+    pub const fn len(&self) -> u32 {
+        // every way of constructing a `Str32` checks this, so it only needs to be verified in debug builds
+        debug_assert!(self.0.len() as u64 <= u32::MAX as u64);
+        self.0.len() as u32
+    }
+
+    /// Returns whether the `Str32` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "".try_into().unwrap();
+    /// assert!(s.is_empty());
+    ///
+    /// const EMPTY: bool = Str32::from_str_unchecked("").is_empty();
+    /// assert!(EMPTY);
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the characters of the `Str32`.
+    ///
+    /// The returned [`std::str::Chars`] is a nameable type, so it can be stored in a struct.
+    pub fn chars(&self) -> std::str::Chars<'_> {
+        self.0.chars()
+    }
+
+    /// Returns the number of `char`s in the `Str32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "aé😀".try_into().unwrap();
+    /// assert_eq!(3, s.char_count());
+    /// ```
+    #[must_use]
+    pub fn char_count(&self) -> u32 {
+        self.char_indices().count().try_into().unwrap()
+    }
+
+    /// Returns the length of the `Str32` in UTF-16 code units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "😀".try_into().unwrap();
+    /// assert_eq!(2, s.utf16_len());
+    /// let s: &Str32 = "aé".try_into().unwrap();
+    /// assert_eq!(2, s.utf16_len());
+    /// ```
+    #[must_use]
+    pub fn utf16_len(&self) -> u32 {
+        self.0.encode_utf16().count().try_into().unwrap()
+    }
+
+    /// Counts the `char`s of the `Str32`, stopping once `limit` is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "a long string".try_into().unwrap();
+    /// assert_eq!(3, s.char_count_at_most(3));
+    /// assert_eq!(13, s.char_count_at_most(100));
+    /// ```
+    #[must_use]
+    pub fn char_count_at_most(&self, limit: u32) -> u32 {
+        self.0
+            .chars()
+            .take(limit.into_usize())
+            .count()
+            .try_into()
+            .unwrap()
+    }
+
+    /// Returns the number of consecutive occurrences of `ch` at the start of the `Str32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "    code".try_into().unwrap();
+    /// assert_eq!(4, s.leading_run(' '));
+    /// assert_eq!(0, s.leading_run('\t'));
+    /// ```
+    #[must_use]
+    pub fn leading_run(&self, ch: char) -> u32 {
+        self.0
+            .chars()
+            .take_while(|&c| c == ch)
+            .count()
+            .try_into()
+            .unwrap()
+    }
+
+    /// Returns an iterator over the characters of the `Str32`, in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "abc".try_into().unwrap();
+    /// let mut iter = s.chars_rev();
+    /// assert_eq!(Some('c'), iter.next());
+    /// assert_eq!(Some('b'), iter.next());
+    /// assert_eq!(Some('a'), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    pub fn chars_rev(&self) -> std::iter::Rev<std::str::Chars<'_>> {
+        self.0.chars().rev()
+    }
+
+    /// Returns an iterator over the Unicode scalar values of the `Str32`, as `u32`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "A€".try_into().unwrap();
+    /// let mut iter = s.code_points();
+    /// assert_eq!(Some(0x41), iter.next());
+    /// assert_eq!(Some(0x20AC), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    pub fn code_points(&self) -> impl DoubleEndedIterator<Item = u32> + '_ {
+        self.0.chars().map(u32::from)
+    }
+
+    /// Returns an iterator over the characters of the `Str32`, and their byte indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "aé€".try_into().unwrap();
+    /// let v: Vec<_> = s.char_indices().collect();
+    /// assert_eq!(vec![(0, 'a'), (1, 'é'), (3, '€')], v);
+    /// assert_eq!(s.chars().count(), s.char_indices().count());
+    /// ```
+    #[must_use]
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        CharIndices::new(self)
+    }
+
+    /// Returns an iterator over the lines of a `&Str32`.
+    #[must_use]
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &Self> + '_ {
+        self.0.lines().map(|line| line.try_into().unwrap())
+    }
+
+    /// Returns an iterator over the lines of a `&Str32`, where lines may end in `\n`, `\r\n`, or a lone `\r`.
+    ///
+    /// The line terminators are not included in the returned lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "a\rb\r\nc\nd".try_into().unwrap();
+    /// let lines: Vec<_> = s.lines_any().map(Str32::as_str).collect();
+    /// assert_eq!(vec!["a", "b", "c", "d"], lines);
+    /// ```
+    pub fn lines_any(&self) -> impl Iterator<Item = &Self> + '_ {
+        let mut rest = &self.0;
+        std::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+            let (line, tail) = match rest.find(['\n', '\r']) {
+                Some(i) if rest[i..].starts_with("\r\n") => (&rest[..i], &rest[i + 2..]),
+                Some(i) => (&rest[..i], &rest[i + 1..]),
+                None => (rest, ""),
+            };
+            rest = tail;
+            Some(line.try_into().unwrap())
+        })
+    }
+
+    /// Returns an iterator over the ASCII-whitespace-delimited words of a `&Str32`.
+    #[must_use]
+    pub fn split_ascii_whitespace(&self) -> impl DoubleEndedIterator<Item = &Self> + '_ {
+        self.0
+            .split_ascii_whitespace()
+            .map(|line| line.try_into().unwrap())
+    }
+
+    /// Splits a `&Str32` in two at the given byte index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is not a UTF-8 code point boundary.
+    #[must_use]
+    pub fn split_at(&self, mid: u32) -> (&Self, &Self) {
+        let (s1, s2) = self.0.split_at(mid.into_usize());
+        (s1.try_into().unwrap(), s2.try_into().unwrap())
+    }
+
+    /// Splits a `&Str32` in two at the given byte index, without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EditError::OutOfBounds`] if `mid` is larger than the length of the string, or [`EditError::NotCharBoundary`] if `mid` is not a UTF-8 code point boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::{EditError, Str32};
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "aé".try_into().unwrap();
+    /// let (a, b) = s.try_split_at(1).unwrap();
+    /// assert_eq!(("a", "é"), (a.as_str(), b.as_str()));
+    /// assert_eq!(Err(EditError::NotCharBoundary), s.try_split_at(2));
+    /// assert_eq!(Err(EditError::OutOfBounds), s.try_split_at(4));
+    /// ```
+    pub fn try_split_at(&self, mid: u32) -> Result<(&Self, &Self), EditError> {
+        if mid > self.len() {
+            return Err(EditError::OutOfBounds);
+        }
+        if !self.is_char_boundary(mid) {
+            return Err(EditError::NotCharBoundary);
+        }
+        Ok(self.split_at(mid))
+    }
+
+    /// Splits a `&mut Str32` in two at the given byte index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is not a UTF-8 code point boundary.
+    #[must_use]
+    pub fn split_at_mut(&mut self, mid: u32) -> (&mut Self, &mut Self) {
+        let (s1, s2) = self.0.split_at_mut(mid.into_usize());
+        (s1.try_into().unwrap(), s2.try_into().unwrap())
+    }
+
+    /// Splits a `&Str32` in two after the given number of `char`s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `char_mid` is greater than the number of `char`s in the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "áéí".try_into().unwrap();
+    /// let (first, rest) = s.split_at_char(1);
+    /// assert_eq!("á", first);
+    /// assert_eq!("éí", rest);
+    /// ```
+    #[must_use]
+    pub fn split_at_char(&self, char_mid: u32) -> (&Self, &Self) {
+        let mid = self
+            .byte_of_char(char_mid)
+            .expect("char index out of bounds");
+        let (s1, s2) = self.0.split_at(mid.into_usize());
+        (s1.try_into().unwrap(), s2.try_into().unwrap())
+    }
+
+    /// Returns a substring with the given prefix removed, or `None` if the string does not start with `pat`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "foo:bar".try_into().unwrap();
+    /// assert_eq!(Some("bar"), s.strip_prefix("foo:").map(Str32::as_str));
+    /// assert_eq!(None, s.strip_prefix('b'));
+    /// ```
+    #[must_use]
+    pub fn strip_prefix<P: Pattern>(&self, mut pat: P) -> Option<&Self> {
+        pat.prefix_len_in(&self.0)
+            .map(|n| self.0[n..].try_into().unwrap())
+    }
+
+    /// Returns a substring with the given suffix removed, or `None` if the string does not end with `pat`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "foo:bar".try_into().unwrap();
+    /// assert_eq!(Some("foo"), s.strip_suffix(":bar").map(Str32::as_str));
+    /// assert_eq!(None, s.strip_suffix('f'));
+    /// ```
+    #[must_use]
+    pub fn strip_suffix<P: Pattern>(&self, mut pat: P) -> Option<&Self> {
+        pat.suffix_len_in(&self.0)
+            .map(|n| self.0[..self.0.len() - n].try_into().unwrap())
+    }
+
+    /// Returns a substring with a leading UTF-8 byte order mark (`U+FEFF`) removed, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "\u{FEFF}text".try_into().unwrap();
+    /// assert_eq!("text", s.strip_bom());
+    /// let s: &Str32 = "text".try_into().unwrap();
+    /// assert_eq!("text", s.strip_bom());
+    /// ```
+    #[must_use]
+    pub fn strip_bom(&self) -> &Self {
+        self.strip_prefix('\u{FEFF}').unwrap_or(self)
+    }
+
+    /// Returns a substring with a single trailing carriage return (`'\r'`) removed, if there is one.
+    ///
+    /// This is useful for normalizing the line endings of pieces split on `'\n'`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "abc\r".try_into().unwrap();
+    /// assert_eq!("abc", s.trim_cr());
+    /// let s: &Str32 = "abc".try_into().unwrap();
+    /// assert_eq!("abc", s.trim_cr());
+    /// let s: &Str32 = "abc\r\r".try_into().unwrap();
+    /// assert_eq!("abc\r", s.trim_cr());
+    /// ```
+    #[must_use]
+    pub fn trim_cr(&self) -> &Self {
+        self.strip_suffix('\r').unwrap_or(self)
+    }
+
+    /// Splits off a matching prefix, returning both the matched prefix (if any) and the remainder.
+    ///
+    /// If the string does not start with `pat`, the remainder is the whole string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "--flag=value".try_into().unwrap();
+    /// let (prefix, rest) = s.split_prefix("--");
+    /// assert_eq!(Some("--"), prefix.map(Str32::as_str));
+    /// assert_eq!("flag=value", rest);
+    ///
+    /// let (prefix, rest) = rest.split_prefix("--");
+    /// assert_eq!(None, prefix);
+    /// assert_eq!("flag=value", rest);
+    /// ```
+    #[must_use]
+    pub fn split_prefix<P: Pattern>(&self, mut pat: P) -> (Option<&Self>, &Self) {
+        match pat.prefix_len_in(&self.0) {
+            Some(n) => {
+                let (prefix, rest) = self.0.split_at(n);
+                (Some(prefix.try_into().unwrap()), rest.try_into().unwrap())
+            }
+            None => (None, self),
+        }
+    }
+
+    /// Splits the string on the first match of `pat`, returning owned copies of the parts before and after the match.
+    ///
+    /// Returns `None` if `pat` does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "k=v=w".try_into().unwrap();
+    /// let (k, v) = s.split_once_owned('=').unwrap();
+    /// assert_eq!("k", k);
+    /// assert_eq!("v=w", v);
+    /// assert_eq!(None, s.split_once_owned(':'));
+    /// ```
+    #[must_use]
+    pub fn split_once_owned<P: Pattern>(&self, mut pat: P) -> Option<(String32, String32)> {
+        let (start, end) = pat.find_in(&self.0, 0)?;
+        Some((
+            self.0[..start].try_into().unwrap(),
+            self.0[end..].try_into().unwrap(),
+        ))
+    }
+
+    /// Converts a `char` index into a byte index.
+    ///
+    /// Returns the length of the string if `char_idx` is equal to the number of `char`s, and `None` if it is greater.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "áéí".try_into().unwrap();
+    /// assert_eq!(Some(0), s.byte_of_char(0));
+    /// assert_eq!(Some(2), s.byte_of_char(1));
+    /// assert_eq!(Some(6), s.byte_of_char(3));
+    /// assert_eq!(None, s.byte_of_char(4));
+    /// ```
+    #[must_use]
+    pub fn byte_of_char(&self, char_idx: u32) -> Option<u32> {
+        self.char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(self.len()))
+            .nth(char_idx.into_usize())
+    }
+
+    /// Converts a byte index into a `char` index.
+    ///
+    /// Returns `None` if `byte_idx` is not a UTF-8 code point boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "áéí".try_into().unwrap();
+    /// assert_eq!(Some(0), s.char_of_byte(0));
+    /// assert_eq!(Some(1), s.char_of_byte(2));
+    /// assert_eq!(Some(3), s.char_of_byte(6));
+    /// assert_eq!(None, s.char_of_byte(3));
+    /// assert_eq!(None, s.char_of_byte(7));
+    /// ```
+    #[must_use]
+    pub fn char_of_byte(&self, byte_idx: u32) -> Option<u32> {
+        self.0
+            .get(..byte_idx.into_usize())
+            .map(|s| s.chars().count().try_into().unwrap())
+    }
+
+    /// Returns an iterator over the non-overlapping matches of `pat`, along with the `char` index at which each match starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "áXáX".try_into().unwrap();
+    /// let v: Vec<_> = s.match_char_indices("X").map(|(i, m)| (i, m.as_str())).collect();
+    /// assert_eq!(vec![(1, "X"), (3, "X")], v);
+    /// ```
+    pub fn match_char_indices<'a, P>(&'a self, pat: P) -> impl Iterator<Item = (u32, &'a Self)> + 'a
+    where
+        P: Pattern + 'a,
+    {
+        let mut chars = 0;
+        let mut last = 0;
+        MatchRanges::new(&self.0, pat).map(move |(start, end)| {
+            chars += self.0[last..start].chars().count();
+            last = start;
+            (
+                chars.try_into().unwrap(),
+                self.0[start..end].try_into().unwrap(),
+            )
+        })
+    }
+
+    /// Returns an iterator over the whitespace-delimited words of a `&Str32`.
+    #[must_use]
+    pub fn split_whitespace(&self) -> impl DoubleEndedIterator<Item = &Self> + '_ {
+        self.0
+            .split_whitespace()
+            .map(|line| line.try_into().unwrap())
+    }
+
+    /// Returns an iterator over the substrings separated by matches of `pat`, along with the byte index at which each substring starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "ab,cd".try_into().unwrap();
+    /// let v: Vec<_> = s.split_indices(',').map(|(i, w)| (i, w.as_str())).collect();
+    /// assert_eq!(vec![(0, "ab"), (3, "cd")], v);
+    /// ```
+    pub fn split_indices<'a, P>(&'a self, pat: P) -> impl Iterator<Item = (u32, &'a Self)> + 'a
+    where
+        P: Pattern + 'a,
+    {
+        let mut matches = MatchRanges::new(&self.0, pat);
+        let mut start = Some(0);
+        std::iter::from_fn(move || {
+            let piece_start = start?;
+            let piece_end = match matches.next() {
+                Some((match_start, match_end)) => {
+                    start = Some(match_end);
+                    match_start
+                }
+                None => {
+                    start = None;
+                    self.0.len()
+                }
+            };
+            let piece = &self.0[piece_start..piece_end];
+            Some((piece_start.try_into().unwrap(), piece.try_into().unwrap()))
+        })
+    }
+
+    /// Returns an iterator over both the substrings separated by matches of `pat` and the matches themselves, in order.
+    ///
+    /// Each item is tagged with `true` if it is a match of `pat`, and `false` if it is text between matches.
+    /// Empty text between adjacent matches is skipped, so concatenating all of the items reconstructs the original string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "a,b".try_into().unwrap();
+    /// let v: Vec<_> = s.split_keep(',').map(|(m, p)| (m, p.as_str())).collect();
+    /// assert_eq!(vec![(false, "a"), (true, ","), (false, "b")], v);
+    ///
+    /// let s: &Str32 = ",a,,".try_into().unwrap();
+    /// let v: Vec<_> = s.split_keep(',').map(|(m, p)| (m, p.as_str())).collect();
+    /// assert_eq!(vec![(true, ","), (false, "a"), (true, ","), (true, ",")], v);
+    /// ```
+    pub fn split_keep<'a, P>(&'a self, pat: P) -> impl Iterator<Item = (bool, &'a Self)> + 'a
+    where
+        P: Pattern + 'a,
+    {
+        let mut matches = MatchRanges::new(&self.0, pat);
+        let mut pos = Some(0);
+        let mut pending = None;
+        let piece = move |start: usize, end: usize| self.0[start..end].try_into().unwrap();
+        std::iter::from_fn(move || {
+            if let Some((start, end)) = pending.take() {
+                return Some((true, piece(start, end)));
+            }
+            let start = pos?;
+            match matches.next() {
+                Some((match_start, match_end)) => {
+                    pos = Some(match_end);
+                    if match_start > start {
+                        pending = Some((match_start, match_end));
+                        Some((false, piece(start, match_start)))
+                    } else {
+                        Some((true, piece(match_start, match_end)))
+                    }
+                }
+                None => {
+                    pos = None;
+                    if start < self.0.len() {
+                        Some((false, piece(start, self.0.len())))
+                    } else {
+                        None
+                    }
+                }
+            }
+        })
+    }
+
+    /// Replaces each non-overlapping match of `pat` with the string computed by `f` from that match, returning a new [`String32`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "{n}. foo, {n}. bar".try_into().unwrap();
+    /// let mut n = 0;
+    /// let replaced = s.replace_with("{n}", |_| {
+    ///     n += 1;
+    ///     n.to_string()
+    /// });
+    /// assert_eq!("1. foo, 2. bar", replaced);
+    /// ```
+    #[must_use]
+    pub fn replace_with<P, F>(&self, pat: P, mut f: F) -> String32
+    where
+        P: Pattern,
+        F: FnMut(&Self) -> String,
+    {
+        let mut ret = String::with_capacity(self.0.len());
+        let mut last = 0;
+        for (start, end) in MatchRanges::new(&self.0, pat) {
+            ret.push_str(&self.0[last..start]);
+            ret.push_str(&f(self.0[start..end].try_into().unwrap()));
+            last = end;
+        }
+        ret.push_str(&self.0[last..]);
+        ret.try_into().unwrap()
+    }
+
+    /// Returns an iterator over the whitespace-delimited words of a `&Str32`, along with the byte index at which each word starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "  foo bar ".try_into().unwrap();
+    /// let v: Vec<_> = s.split_whitespace_indices().map(|(i, w)| (i, w.as_str())).collect();
+    /// assert_eq!(vec![(2, "foo"), (6, "bar")], v);
+    /// ```
+    pub fn split_whitespace_indices(&self) -> impl DoubleEndedIterator<Item = (u32, &Self)> + '_ {
+        self.0
+            .split_whitespace()
+            .map(move |word| (self.offset_of(word), word.try_into().unwrap()))
+    }
+
+    /// Returns the byte index at which `sub` starts, where `sub` must be a subslice of this `Str32`.
+    fn offset_of(&self, sub: &str) -> u32 {
+        (sub.as_ptr() as usize - self.as_ptr() as usize)
+            .try_into()
+            .unwrap()
+    }
+
+    /// Checks if two string slices are equal, ignoring ASCII case mismatches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "True".try_into().unwrap();
+    /// assert!(s.eq_ignore_ascii_case("TRUE"));
+    /// assert!(s.eq_ignore_ascii_case(s));
+    /// assert!(!s.eq_ignore_ascii_case("FALSE"));
+    /// ```
+    #[must_use]
+    pub fn eq_ignore_ascii_case<S>(&self, other: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        self.0.eq_ignore_ascii_case(other.as_ref())
     }
 
-    /// Returns the length of the `Str32` in bytes.
+    /// Checks if two string slices are equal, ignoring a single trailing line ending (`"\n"` or `"\r\n"`) on either side.
     ///
     /// # Examples
     ///
     /// ```
     /// # use string32::Str32;
     /// # use std::convert::TryInto;
-    /// let s: &Str32 = "test".try_into().unwrap();
-    /// assert_eq!(4, s.len());
+    /// let s: &Str32 = "abc\n".try_into().unwrap();
+    /// assert!(s.eq_ignoring_trailing_newline("abc"));
+    /// assert!(s.eq_ignoring_trailing_newline("abc\r\n"));
+    /// assert!(!s.eq_ignoring_trailing_newline("abc\n\n"));
+    /// let s: &Str32 = "abc".try_into().unwrap();
+    /// assert!(!s.eq_ignoring_trailing_newline("abd"));
     /// ```
     #[must_use]
-    pub fn len(&self) -> u32 {
-        self.0.len().try_into().unwrap()
+    pub fn eq_ignoring_trailing_newline<S>(&self, other: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        fn strip(s: &str) -> &str {
+            s.strip_suffix('\n')
+                .map_or(s, |s| s.strip_suffix('\r').unwrap_or(s))
+        }
+        strip(&self.0) == strip(other.as_ref())
     }
 
-    /// Returns whether the `Str32` is empty.
+    /// Checks if two string slices are equal, ignoring case mismatches according to Unicode case folding.
+    ///
+    /// Unlike [`Str32::eq_ignore_ascii_case`], this uses full case folding, so a single `char` may fold to several (e.g. `'ß'` folds to `"ss"`).
+    ///
+    /// This method requires the `unicase` feature.
     ///
     /// # Examples
     ///
     /// ```
     /// # use string32::Str32;
     /// # use std::convert::TryInto;
-    /// let s: &Str32 = "".try_into().unwrap();
-    /// assert!(s.is_empty());
+    /// let s: &Str32 = "STRASSE".try_into().unwrap();
+    /// assert!(s.eq_ignore_case("strasse"));
+    /// assert!(s.eq_ignore_case("straße"));
+    /// assert!(s.eq_ignore_case("Straße"));
+    /// assert!(!s.eq_ignore_case("strase"));
     /// ```
+    #[cfg(feature = "unicase")]
     #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn eq_ignore_case<S>(&self, other: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        unicase::eq(&self.0, other.as_ref())
     }
 
-    /// Returns an iterator over the characters of the `Str32`.
-    pub fn chars(&self) -> std::str::Chars {
-        self.0.chars()
+    /// Return an iterator over the string slice's chars, each escaped according to `char::escape_debug`.
+    #[must_use]
+    pub fn escape_debug(&self) -> std::str::EscapeDebug<'_> {
+        self.0.escape_debug()
     }
 
-    /// Returns an iterator over the characters of the `Str32`, and their byte indices.
+    /// Return an iterator over the string slice's chars, each escaped according to `char::escape_default`.
     #[must_use]
-    pub fn char_indices(&self) -> impl DoubleEndedIterator<Item = (u32, char)> + '_ {
-        self.0
-            .char_indices()
-            .map(|(i, c)| (i.try_into().unwrap(), c))
+    pub fn escape_default(&self) -> std::str::EscapeDefault<'_> {
+        self.0.escape_default()
     }
 
-    /// Returns an iterator over the lines of a `&Str32`.
+    /// Return an iterator over the string slice's chars, each escaped according to `char::escape_unicode`.
     #[must_use]
-    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &Self> + '_ {
-        self.0.lines().map(|line| line.try_into().unwrap())
+    pub fn escape_unicode(&self) -> std::str::EscapeUnicode<'_> {
+        self.0.escape_unicode()
     }
 
-    /// Returns an iterator over the ASCII-whitespace-delimited words of a `&Str32`.
+    /// Returns whether the given index corresponds to a `char` boundary.
     #[must_use]
-    pub fn split_ascii_whitespace(&self) -> impl DoubleEndedIterator<Item = &Self> + '_ {
-        self.0
-            .split_ascii_whitespace()
-            .map(|line| line.try_into().unwrap())
+    pub fn is_char_boundary(&self, index: u32) -> bool {
+        self.0.is_char_boundary(index.into_usize())
     }
 
-    /// Splits a `&Str32` in two at the given byte index.
+    /// Returns whether all characters in this `Str32` are ASCII.
     ///
-    /// # Panics
+    /// This is equivalent to [`str::is_ascii`], but checks a `usize`-sized word of bytes at a time.
     ///
-    /// Panics if `mid` is not a UTF-8 code point boundary.
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "hello, world".try_into().unwrap();
+    /// assert!(s.is_ascii_fast());
+    /// let s: &Str32 = "hello, wörld".try_into().unwrap();
+    /// assert!(!s.is_ascii_fast());
+    /// ```
     #[must_use]
-    pub fn split_at(&self, mid: u32) -> (&Self, &Self) {
-        let (s1, s2) = self.0.split_at(mid.into_usize());
-        (s1.try_into().unwrap(), s2.try_into().unwrap())
+    pub fn is_ascii_fast(&self) -> bool {
+        const WORD: usize = mem::size_of::<usize>();
+        const HIGH_BITS: usize = usize::MAX / 0xFF * 0x80;
+
+        let mut words = self.as_bytes().chunks_exact(WORD);
+        words.all(|w| usize::from_ne_bytes(w.try_into().unwrap()) & HIGH_BITS == 0)
+            && words.remainder().is_ascii()
     }
 
-    /// Splits a `&mut Str32` in two at the given byte index.
+    /// Returns the nearest `char` boundary strictly before the given byte index, or `None` if `i` is zero.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Panics if `mid` is not a UTF-8 code point boundary.
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "aé€".try_into().unwrap();
+    /// assert_eq!(Some(3), s.prev_char_boundary(6));
+    /// assert_eq!(Some(1), s.prev_char_boundary(3));
+    /// assert_eq!(Some(1), s.prev_char_boundary(2));
+    /// assert_eq!(Some(0), s.prev_char_boundary(1));
+    /// assert_eq!(None, s.prev_char_boundary(0));
+    /// ```
     #[must_use]
-    pub fn split_at_mut(&mut self, mid: u32) -> (&mut Self, &mut Self) {
-        let (s1, s2) = self.0.split_at_mut(mid.into_usize());
-        (s1.try_into().unwrap(), s2.try_into().unwrap())
+    pub fn prev_char_boundary(&self, i: u32) -> Option<u32> {
+        i.checked_sub(1).map(|i| self.floor_char_boundary(i))
     }
 
-    /// Returns an iterator over the whitespace-delimited words of a `&Str32`.
+    /// Returns the nearest `char` boundary strictly after the given byte index, or `None` if `i` is at or past the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "aé€".try_into().unwrap();
+    /// assert_eq!(Some(1), s.next_char_boundary(0));
+    /// assert_eq!(Some(3), s.next_char_boundary(1));
+    /// assert_eq!(Some(3), s.next_char_boundary(2));
+    /// assert_eq!(Some(6), s.next_char_boundary(3));
+    /// assert_eq!(None, s.next_char_boundary(6));
+    /// ```
     #[must_use]
-    pub fn split_whitespace(&self) -> impl DoubleEndedIterator<Item = &Self> + '_ {
-        self.0
-            .split_whitespace()
-            .map(|line| line.try_into().unwrap())
+    pub fn next_char_boundary(&self, i: u32) -> Option<u32> {
+        if i < self.len() {
+            Some(self.ceil_char_boundary(i + 1))
+        } else {
+            None
+        }
     }
 
-    /// Checks if two string slices are equal, ignoring ASCII case mismatches.
+    /// Returns the largest `char` boundary less than or equal to `i`, clamped to the length of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "aé€".try_into().unwrap();
+    /// assert_eq!(1, s.floor_char_boundary(2));
+    /// assert_eq!(3, s.floor_char_boundary(5));
+    /// assert_eq!(6, s.floor_char_boundary(9));
+    /// ```
     #[must_use]
-    pub fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
-        self.0.eq_ignore_ascii_case(&other.0)
+    pub fn floor_char_boundary(&self, i: u32) -> u32 {
+        if i >= self.len() {
+            self.len()
+        } else {
+            (0..=i).rev().find(|&j| self.is_char_boundary(j)).unwrap()
+        }
     }
 
-    /// Return an iterator over the string slice's chars, each escaped according to `char::escape_debug`.
+    /// Returns the smallest `char` boundary greater than or equal to `i`, clamped to the length of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "aé€".try_into().unwrap();
+    /// assert_eq!(3, s.ceil_char_boundary(2));
+    /// assert_eq!(6, s.ceil_char_boundary(4));
+    /// assert_eq!(6, s.ceil_char_boundary(9));
+    /// ```
     #[must_use]
-    pub fn escape_debug(&self) -> std::str::EscapeDebug<'_> {
-        self.0.escape_debug()
+    pub fn ceil_char_boundary(&self, i: u32) -> u32 {
+        if i >= self.len() {
+            self.len()
+        } else {
+            (i..self.len())
+                .find(|&j| self.is_char_boundary(j))
+                .unwrap_or(self.len())
+        }
     }
 
-    /// Return an iterator over the string slice's chars, each escaped according to `char::escape_default`.
+    /// Moves a byte index forward to the nearest `char` boundary, for resynchronizing after seeking to an arbitrary byte.
+    ///
+    /// This is an alias for [`Str32::ceil_char_boundary`].
     #[must_use]
-    pub fn escape_default(&self) -> std::str::EscapeDefault<'_> {
-        self.0.escape_default()
+    pub fn resync_forward(&self, byte: u32) -> u32 {
+        self.ceil_char_boundary(byte)
     }
 
-    /// Return an iterator over the string slice's chars, each escaped according to `char::escape_unicode`.
+    /// Moves a byte index backward to the nearest `char` boundary, for resynchronizing after seeking to an arbitrary byte.
+    ///
+    /// This is an alias for [`Str32::floor_char_boundary`].
     #[must_use]
-    pub fn escape_unicode(&self) -> std::str::EscapeUnicode<'_> {
-        self.0.escape_unicode()
+    pub fn resync_backward(&self, byte: u32) -> u32 {
+        self.floor_char_boundary(byte)
     }
 
-    /// Returns whether the given index corresponds to a `char` boundary.
+    /// Returns the length in bytes of the longest common prefix of two string slices.
+    ///
+    /// The result is always a `char` boundary of both strings, so the prefix never ends in the middle of a multi-byte `char`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s1: &Str32 = "foobar".try_into().unwrap();
+    /// let s2: &Str32 = "foobaz".try_into().unwrap();
+    /// assert_eq!(5, s1.common_prefix_len(s2));
+    /// assert_eq!(6, s1.common_prefix_len(s1));
+    ///
+    /// // 'é' and 'ê' share their first UTF-8 byte
+    /// let s1: &Str32 = "aé".try_into().unwrap();
+    /// let s2: &Str32 = "aê".try_into().unwrap();
+    /// assert_eq!(1, s1.common_prefix_len(s2));
+    /// ```
     #[must_use]
-    pub fn is_char_boundary(&self, index: u32) -> bool {
-        self.0.is_char_boundary(index.into_usize())
+    pub fn common_prefix_len(&self, other: &Self) -> u32 {
+        let n = self
+            .as_bytes()
+            .iter()
+            .zip(other.as_bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        self.floor_char_boundary(n.try_into().unwrap())
     }
 
     /// Converts all uppercase ASCII characters to lowercase.
@@ -249,6 +1324,59 @@ impl Str32 {
         self.0.make_ascii_uppercase()
     }
 
+    /// Replaces every occurrence of the ASCII byte `from` with the ASCII byte `to`, in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `from` or `to` is not an ASCII byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("a-b-é").unwrap();
+    /// s.replace_ascii_in_place(b'-', b'_');
+    /// assert_eq!("a_b_é", s);
+    /// ```
+    pub fn replace_ascii_in_place(&mut self, from: u8, to: u8) {
+        assert!(from.is_ascii(), "`from` must be an ASCII byte");
+        self.replace_ascii_if(|b| b == from, to);
+    }
+
+    /// Replaces every ASCII byte for which `pred` returns `true` with the ASCII byte `to`, in place.
+    ///
+    /// `pred` is only called on ASCII bytes, since replacing part of a multi-byte UTF-8 sequence would not leave valid UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `to` is not an ASCII byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("a1b2").unwrap();
+    /// s.replace_ascii_if(|b| b.is_ascii_digit(), b'*');
+    /// assert_eq!("a*b*", s);
+    /// ```
+    pub fn replace_ascii_if<F>(&mut self, mut pred: F, to: u8)
+    where
+        F: FnMut(u8) -> bool,
+    {
+        assert!(to.is_ascii(), "`to` must be an ASCII byte");
+        let bytes = unsafe {
+            // safety: only ASCII bytes are replaced, and only with ASCII bytes
+            self.as_bytes_mut()
+        };
+        for b in bytes {
+            if b.is_ascii() && pred(*b) {
+                *b = to;
+            }
+        }
+    }
+
     /// Parses a `&Str32` slice into another type.
     ///
     /// # Errors
@@ -260,6 +1388,115 @@ impl Str32 {
         self.0.parse()
     }
 
+    /// Parses a `&Str32` slice as a signed integer in the given base.
+    ///
+    /// # Errors
+    ///
+    /// See [`i64::from_str_radix`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "-ff".try_into().unwrap();
+    /// assert_eq!(Ok(-255), s.parse_int_radix(16));
+    /// ```
+    pub fn parse_int_radix(&self, radix: u32) -> Result<i64, std::num::ParseIntError> {
+        i64::from_str_radix(&self.0, radix)
+    }
+
+    /// Parses a `&Str32` slice as an unsigned integer in the given base.
+    ///
+    /// # Errors
+    ///
+    /// See [`u64::from_str_radix`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "ff".try_into().unwrap();
+    /// assert_eq!(Ok(255), s.parse_uint_radix(16));
+    /// ```
+    pub fn parse_uint_radix(&self, radix: u32) -> Result<u64, std::num::ParseIntError> {
+        u64::from_str_radix(&self.0, radix)
+    }
+
+    /// Parses the given byte range as an unsigned decimal integer made up only of ASCII digits.
+    ///
+    /// Returns `None` if the range is empty or out of bounds, if any byte in it is not an ASCII digit, or if the number overflows a `u32`.
+    /// Unlike [`Str32::parse`], no sign is accepted, and the range need not lie on `char` boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "id=00042;".try_into().unwrap();
+    /// assert_eq!(Some(42), s.read_ascii_u32(3..8));
+    /// assert_eq!(None, s.read_ascii_u32(2..8));
+    /// assert_eq!(None, s.read_ascii_u32(3..3));
+    /// assert_eq!(None, s.read_ascii_u32(3..20));
+    /// ```
+    #[must_use]
+    pub fn read_ascii_u32<R>(&self, range: R) -> Option<u32>
+    where
+        R: RangeBounds<u32>,
+    {
+        let digits = self.as_bytes().get(usize_bounds(&range))?;
+        if digits.is_empty() {
+            return None;
+        }
+        digits.iter().try_fold(0_u32, |n, &b| {
+            if b.is_ascii_digit() {
+                n.checked_mul(10)?.checked_add(u32::from(b - b'0'))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Parses a decimal integer, with an optional leading `+` or `-` sign, off the front of a `&Str32`, returning it along with the unconsumed remainder.
+    ///
+    /// Returns `None` if the string does not start with a digit (after the optional sign), or if the number overflows an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "42rest".try_into().unwrap();
+    /// assert_eq!(Some((42, "rest".try_into().unwrap())), s.parse_prefix_int());
+    /// let s: &Str32 = "-7".try_into().unwrap();
+    /// assert_eq!(Some((-7, "".try_into().unwrap())), s.parse_prefix_int());
+    /// let s: &Str32 = "-x".try_into().unwrap();
+    /// assert_eq!(None, s.parse_prefix_int());
+    /// ```
+    #[must_use]
+    pub fn parse_prefix_int(&self) -> Option<(i64, &Self)> {
+        let bytes = self.as_bytes();
+        let sign = usize::from(matches!(bytes.first(), Some(b'+') | Some(b'-')));
+        let digits = bytes[sign..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digits == 0 {
+            return None;
+        }
+        let (num, rest) = self.0.split_at(sign + digits);
+        num.parse().ok().map(|n| (n, rest.try_into().unwrap()))
+    }
+
     /// Create a [`String32`] formed by `n` repetitions of this string slice.
     ///
     /// # Panics
@@ -270,6 +1507,37 @@ impl Str32 {
         self.0.repeat(n.into_usize()).try_into().unwrap()
     }
 
+    /// Append `n` repetitions of this string slice to the end of `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting [`String32`] would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::{Str32, String32};
+    /// # use std::convert::{TryFrom, TryInto};
+    /// let mut s = String32::try_from("x").unwrap();
+    /// let dash: &Str32 = "-".try_into().unwrap();
+    /// dash.repeat_into(5, &mut s);
+    /// assert_eq!("x-----", s);
+    /// ```
+    pub fn repeat_into(&self, n: u32, out: &mut String32) {
+        if self.is_empty() || n == 0 {
+            return;
+        }
+        let additional = self
+            .len()
+            .checked_mul(n)
+            .filter(|&additional| out.len().checked_add(additional).is_some())
+            .expect("string too large for u32-indexed buffer");
+        out.reserve(additional);
+        for _ in 0..n {
+            out.push_str(self);
+        }
+    }
+
     /// Returns a lowercase equivalent of this `&Str32` as a new [`String32`].
     ///
     /// # Examples
@@ -330,6 +1598,50 @@ impl Str32 {
         self.0.to_ascii_uppercase().try_into().unwrap()
     }
 
+    /// Writes a copy of this `&Str32` with each ASCII uppercase character mapped to lowercase into `out`, replacing its contents.
+    ///
+    /// This reuses the allocation of `out`, unlike [`Str32::to_ascii_lowercase`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::{Str32, String32};
+    /// # use std::convert::TryInto;
+    /// let mut out = String32::new();
+    /// for (s, lower) in [("TEST", "test"), ("Hello", "hello")].iter() {
+    ///     let s: &Str32 = (*s).try_into().unwrap();
+    ///     s.ascii_lowercase_into(&mut out);
+    ///     assert_eq!(*lower, out);
+    /// }
+    /// ```
+    pub fn ascii_lowercase_into(&self, out: &mut String32) {
+        out.clear();
+        out.push_str32(self);
+        out.make_ascii_lowercase();
+    }
+
+    /// Writes a copy of this `&Str32` with each ASCII lowercase character mapped to uppercase into `out`, replacing its contents.
+    ///
+    /// This reuses the allocation of `out`, unlike [`Str32::to_ascii_uppercase`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::{Str32, String32};
+    /// # use std::convert::TryInto;
+    /// let mut out = String32::new();
+    /// for (s, upper) in [("test", "TEST"), ("Hello", "HELLO")].iter() {
+    ///     let s: &Str32 = (*s).try_into().unwrap();
+    ///     s.ascii_uppercase_into(&mut out);
+    ///     assert_eq!(*upper, out);
+    /// }
+    /// ```
+    pub fn ascii_uppercase_into(&self, out: &mut String32) {
+        out.clear();
+        out.push_str32(self);
+        out.make_ascii_uppercase();
+    }
+
     /// Returns a substring of this string with leading and trailing whitespace removed.
     ///
     /// # Examples
@@ -375,6 +1687,55 @@ impl Str32 {
         self.0.trim_end().try_into().unwrap()
     }
 
+    /// Returns the `(start, end)` byte range of [`Str32::trim`] within this string.
+    ///
+    /// If the string is entirely whitespace, the range is empty and sits at the end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "  hi  ".try_into().unwrap();
+    /// assert_eq!((2, 4), s.trim_span());
+    /// assert_eq!("hi", &s.as_str()[2..4]);
+    /// ```
+    #[must_use]
+    pub fn trim_span(&self) -> (u32, u32) {
+        let (start, _) = self.trim_start_span();
+        (start, start + self.trim().len())
+    }
+
+    /// Returns the `(start, end)` byte range of [`Str32::trim_start`] within this string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "  hi  ".try_into().unwrap();
+    /// assert_eq!((2, 6), s.trim_start_span());
+    /// ```
+    #[must_use]
+    pub fn trim_start_span(&self) -> (u32, u32) {
+        (self.len() - self.trim_start().len(), self.len())
+    }
+
+    /// Returns the `(start, end)` byte range of [`Str32::trim_end`] within this string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::Str32;
+    /// # use std::convert::TryInto;
+    /// let s: &Str32 = "  hi  ".try_into().unwrap();
+    /// assert_eq!((0, 4), s.trim_end_span());
+    /// ```
+    #[must_use]
+    pub fn trim_end_span(&self) -> (u32, u32) {
+        (0, self.trim_end().len())
+    }
+
     /// Convert a `Box<Str32>` into a [`Box<str>`].
     ///
     /// This method has no overhead in the form of copying or allocating.
@@ -434,19 +1795,43 @@ impl<'a> From<&'a Str32> for &'a str {
 
 impl From<Box<Str32>> for String {
     fn from(b: Box<Str32>) -> Self {
-        b.into()
+        Box::<str>::from(b).into_string()
     }
 }
 
 impl From<Box<Str32>> for Box<str> {
     fn from(b: Box<Str32>) -> Self {
-        b.into()
+        let ptr = Box::into_raw(b) as *mut str;
+        unsafe {
+            // safety: relies on `Box<Str32>` and `Box<str>` having the same layout
+            Self::from_raw(ptr)
+        }
+    }
+}
+
+impl From<&Str32> for Box<Str32> {
+    fn from(s: &Str32) -> Self {
+        let ptr = Box::into_raw(Box::<str>::from(s.as_str())) as *mut Str32;
+        unsafe {
+            // safety: relies on `Box<Str32>` and `Box<str>` having the same layout
+            Self::from_raw(ptr)
+        }
+    }
+}
+
+impl From<&Str32> for Arc<Str32> {
+    fn from(s: &Str32) -> Self {
+        let ptr = Arc::into_raw(Arc::<str>::from(s.as_str())) as *const Str32;
+        unsafe {
+            // safety: relies on `Arc<Str32>` and `Arc<str>` having the same layout
+            Self::from_raw(ptr)
+        }
     }
 }
 
 impl From<Box<Str32>> for Box<[u8]> {
     fn from(b: Box<Str32>) -> Self {
-        b.into()
+        Box::<str>::from(b).into_boxed_bytes()
     }
 }
 
@@ -0,0 +1,54 @@
+use super::String32;
+
+/// A builder for a [`String32`] that records the byte offset at which each appended segment starts.
+///
+/// This is useful when the positions of the pieces are needed after the string is assembled, for example to generate a source map alongside generated code.
+///
+/// # Examples
+///
+/// ```
+/// # use string32::Str32Builder;
+/// let mut b = Str32Builder::new();
+/// assert_eq!(0, b.push_segment("fn "));
+/// assert_eq!(3, b.push_segment("main"));
+/// assert_eq!(7, b.push_segment("() {}"));
+/// assert_eq!(&[0, 3, 7], b.segment_offsets());
+/// assert_eq!("fn main() {}", b.build());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Str32Builder {
+    buf: String32,
+    offsets: Vec<u32>,
+}
+
+impl Str32Builder {
+    /// Create an empty `Str32Builder`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a segment to the string being built, returning the byte offset at which it starts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would require more than [`u32::MAX`] bytes.
+    pub fn push_segment(&mut self, s: &str) -> u32 {
+        let start = self.buf.len();
+        self.buf.push_str(s);
+        self.offsets.push(start);
+        start
+    }
+
+    /// Return the starting byte offsets of the segments pushed so far, in order.
+    #[must_use]
+    pub fn segment_offsets(&self) -> &[u32] {
+        &self.offsets
+    }
+
+    /// Return the assembled `String32`.
+    #[must_use]
+    pub fn build(self) -> String32 {
+        self.buf
+    }
+}
@@ -0,0 +1,42 @@
+use std::convert::TryInto;
+
+use super::{Str32, TryFromStrError};
+
+/// An extension trait for converting string slices into `&Str32`.
+///
+/// # Examples
+///
+/// ```
+/// # use string32::{Str32, StrExt, TryFromStrError};
+/// # fn main() -> Result<(), TryFromStrError> {
+/// let s: &Str32 = "hello".as_str32()?;
+/// assert_eq!(5, s.len());
+/// assert_eq!(s, "hello".as_str32_unchecked());
+/// # Ok(())
+/// # }
+/// ```
+pub trait StrExt {
+    /// Convert to a `&Str32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the string slice occupies more than [`u32::MAX`] bytes.
+    fn as_str32(&self) -> Result<&Str32, TryFromStrError>;
+
+    /// Convert to a `&Str32`, for string slices that are known to be short enough.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string slice occupies more than [`u32::MAX`] bytes.
+    fn as_str32_unchecked(&self) -> &Str32;
+}
+
+impl StrExt for str {
+    fn as_str32(&self) -> Result<&Str32, TryFromStrError> {
+        self.try_into()
+    }
+
+    fn as_str32_unchecked(&self) -> &Str32 {
+        Str32::from_str_unchecked(self)
+    }
+}
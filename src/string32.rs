@@ -1,17 +1,21 @@
 use std::borrow::{Borrow, BorrowMut, Cow};
 use std::cmp;
+use std::collections::TryReserveError;
 use std::convert::{TryFrom, TryInto};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::mem;
-use std::ops;
+use std::ops::{self, RangeBounds};
+use std::ptr;
 use std::string;
+use std::sync::atomic;
+use std::sync::Arc;
 
 use mediumvec::Vec32;
 use usize_cast::IntoUsize;
 
-use super::{Str32, TryFromStrError, TryFromStringError};
+use super::{usize_bounds, EditError, IntoChars, Str32, TryFromStrError, TryFromStringError};
 
 /// A string that is indexed by `u32` instead of `usize`.
 ///
@@ -50,6 +54,34 @@ impl String32 {
         Self(Vec32::with_capacity(cap))
     }
 
+    /// Estimate the number of bytes needed to hold `chars` `char`s, of which a fraction `ascii_ratio` are ASCII.
+    ///
+    /// Each ASCII `char` is counted as 1 byte and each other `char` as 2.5 bytes, which suits mostly-Latin text with some accents.
+    /// The result is meant to be passed to [`String32::with_capacity`] or [`String32::reserve`], and saturates at [`u32::MAX`].
+    ///
+    /// `ascii_ratio` is clamped to the range `0.0..=1.0`, and a NaN ratio is treated as `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// assert_eq!(100, String32::estimate_capacity(100, 1.0));
+    /// assert_eq!(250, String32::estimate_capacity(100, 0.0));
+    /// assert_eq!(115, String32::estimate_capacity(100, 0.9));
+    /// assert_eq!(u32::MAX, String32::estimate_capacity(u32::MAX, 0.5));
+    /// ```
+    #[must_use]
+    pub fn estimate_capacity(chars: u32, ascii_ratio: f32) -> u32 {
+        let ascii_ratio = if ascii_ratio.is_nan() {
+            0.0
+        } else {
+            f64::from(ascii_ratio).clamp(0.0, 1.0)
+        };
+        let bytes_per_char = ascii_ratio + (1.0 - ascii_ratio) * 2.5;
+        // float-to-int `as` casts saturate
+        (f64::from(chars) * bytes_per_char).round() as u32
+    }
+
     /// Return the capacity of this `String32` in bytes.
     ///
     /// # Examples
@@ -66,6 +98,42 @@ impl String32 {
         self.0.capacity()
     }
 
+    /// Return the size in bytes of the buffer allocated by this `String32`.
+    ///
+    /// This is the same as [`String32::capacity`], since each byte of capacity occupies exactly one byte of the allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let s = String32::with_capacity(10);
+    /// assert_eq!(10, s.allocation_size());
+    /// ```
+    #[must_use]
+    pub fn allocation_size(&self) -> u32 {
+        self.capacity()
+    }
+
+    /// Return a reference to the underlying [`Vec32<u8>`](mediumvec::Vec32) of this `String32`.
+    ///
+    /// The bytes of the returned vector are always valid UTF-8. Only shared access is provided, since mutating the vector could break this invariant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let s = String32::try_from("abc").unwrap();
+    /// let v = s.as_vec32();
+    /// assert_eq!(3, v.len());
+    /// assert_eq!(s.capacity(), v.capacity());
+    /// assert_eq!(b"abc", &v[..]);
+    /// ```
+    #[must_use]
+    pub fn as_vec32(&self) -> &Vec32<u8> {
+        &self.0
+    }
+
     /// A helper to call arbitrary [`String`] methods on a `String32.`
     ///
     /// # Panics
@@ -90,6 +158,35 @@ impl String32 {
         ret
     }
 
+    /// A helper to call arbitrary [`String`] methods on a `String32`, without panicking if the result is too large.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the resulting string would require more than [`u32::MAX`] bytes.
+    /// The oversized [`String`] can be recovered with [`TryFromStringError::into_inner`], and this `String32` is left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::new();
+    /// let len = s.try_as_string(|s| {
+    ///     s.push_str("test");
+    ///     s.len()
+    /// });
+    /// assert_eq!(Ok(4), len);
+    /// assert_eq!(s, "test");
+    /// ```
+    pub fn try_as_string<F, T>(&mut self, f: F) -> Result<T, TryFromStringError<String>>
+    where
+        F: FnOnce(&mut String) -> T,
+    {
+        let mut s = mem::take(self).into();
+        let ret = f(&mut s);
+        *self = s.try_into()?;
+        Ok(ret)
+    }
+
     /// Push a `char` to the end of this `String32`.
     ///
     /// # Panics
@@ -105,6 +202,7 @@ impl String32 {
     /// assert_eq!(s, "\n");
     /// ```
     pub fn push(&mut self, ch: char) {
+        self.check_additional(ch.len_utf8());
         self.as_string(|s| s.push(ch));
     }
 
@@ -126,7 +224,164 @@ impl String32 {
     where
         S: AsRef<str>,
     {
-        self.as_string(|st| st.push_str(s.as_ref()));
+        let s = s.as_ref();
+        self.check_additional(s.len());
+        self.as_string(|st| st.push_str(s));
+    }
+
+    /// Panics if appending `additional` bytes would require more than [`u32::MAX`] bytes.
+    fn check_additional(&self, additional: usize) {
+        assert!(
+            u64::from(self.len()) + additional as u64 <= u64::from(u32::MAX),
+            "string too large for u32-indexed buffer"
+        );
+    }
+
+    /// Append `ch` directly to the underlying buffer, without a round trip through [`String`].
+    #[inline]
+    fn push_utf8(&mut self, ch: char) {
+        self.check_additional(ch.len_utf8());
+        if ch.is_ascii() {
+            self.0.push(ch as u8);
+        } else {
+            for &b in ch.encode_utf8(&mut [0; 4]).as_bytes() {
+                self.0.push(b);
+            }
+        }
+    }
+
+    /// Panics if `idx` is out-of-bounds or is not a UTF-8 code point boundary, mentioning `idx` in the message.
+    fn check_char_boundary(&self, idx: u32) {
+        assert!(
+            idx <= self.len(),
+            "byte index {} is out of bounds of string of length {}",
+            idx,
+            self.len()
+        );
+        assert!(
+            self.is_char_boundary(idx),
+            "byte index {} is not a char boundary",
+            idx
+        );
+    }
+
+    /// Append raw bytes to the end of this `String32`, without checking that they are valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// `buf` must be valid UTF-8 on its own, i.e. it must consist of whole UTF-8 encoded `char`s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::new();
+    /// unsafe { s.push_char_bytes("héllo".as_bytes()) };
+    /// assert_eq!("héllo", s);
+    /// ```
+    pub unsafe fn push_char_bytes(&mut self, buf: &[u8]) {
+        self.check_additional(buf.len());
+        debug_assert!(std::str::from_utf8(buf).is_ok());
+        self.0.as_vec(|v| v.extend_from_slice(buf));
+    }
+
+    /// Append a `&Str32` to the end of this `String32`.
+    ///
+    /// Unlike [`String32::push_str`], this is not generic, which helps type inference in generic code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::{Str32, String32};
+    /// # use std::convert::TryInto;
+    /// fn join<'a, I: IntoIterator<Item = &'a Str32>>(iter: I) -> String32 {
+    ///     let mut s = String32::new();
+    ///     for part in iter {
+    ///         s.push_str32(part);
+    ///     }
+    ///     s
+    /// }
+    ///
+    /// let parts: Vec<&Str32> = vec!["ab".try_into().unwrap(), "cd".try_into().unwrap()];
+    /// assert_eq!("abcd", join(parts));
+    /// ```
+    pub fn push_str32(&mut self, s: &Str32) {
+        self.check_additional(s.len().into_usize());
+        self.reserve(s.len());
+        self.0.as_vec(|v| v.extend_from_slice(s.as_bytes()));
+    }
+
+    /// Append `char`s from an iterator of results, stopping at the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first `Err` produced by the iterator.
+    /// Any `char`s appended before the error remain in the `String32`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::new();
+    /// let res = s.try_extend_chars(vec![Ok('a'), Ok('b'), Err("bad"), Ok('c')]);
+    /// assert_eq!(Err("bad"), res);
+    /// assert_eq!("ab", s);
+    /// ```
+    pub fn try_extend_chars<E, I>(&mut self, iter: I) -> Result<(), E>
+    where
+        I: IntoIterator<Item = Result<char, E>>,
+    {
+        self.as_string(|s| {
+            for ch in iter {
+                s.push(ch?);
+            }
+            Ok(())
+        })
+    }
+
+    /// Append string slices from an iterator, skipping any slice that is equal to the one immediately before it.
+    ///
+    /// Only consecutive fragments produced by the iterator are compared, not the existing contents of the `String32`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::new();
+    /// s.extend_dedup(vec!["a", "a", "b"]);
+    /// assert_eq!("ab", s);
+    /// s.extend_dedup(vec!["b", " ", " ", "c", " "]);
+    /// assert_eq!("abb c ", s);
+    /// ```
+    pub fn extend_dedup<'a, I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        self.as_string(|s| {
+            let mut prev = None;
+            for frag in iter {
+                if prev != Some(frag) {
+                    s.push_str(frag);
+                }
+                prev = Some(frag);
+            }
+        });
     }
 
     /// Pop a `char` from the end of this `String32`.
@@ -144,11 +399,35 @@ impl String32 {
         self.as_string(String::pop)
     }
 
+    /// Pop a `char` from the end of this `String32` if it satisfies the given predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("x\n").unwrap();
+    /// assert_eq!(s.pop_if(|c| c == '\n'), Some('\n'));
+    /// assert_eq!(s.pop_if(|c| c == '\n'), None);
+    /// assert_eq!(s, "x");
+    /// ```
+    pub fn pop_if<F>(&mut self, predicate: F) -> Option<char>
+    where
+        F: FnOnce(char) -> bool,
+    {
+        let ch = self.chars().next_back()?;
+        if predicate(ch) {
+            self.pop()
+        } else {
+            None
+        }
+    }
+
     /// Return the `char` at a given byte index.
     ///
     /// # Panics
     ///
-    /// Panics if `idx` is not a UTF-8 code point boundary.
+    /// Panics if `idx` is larger than or equal to the `String32`'s length, or if it is not a UTF-8 code point boundary.
     ///
     /// # Examples
     ///
@@ -160,6 +439,13 @@ impl String32 {
     /// assert_eq!(s, "abc");
     /// ```
     pub fn remove(&mut self, idx: u32) -> char {
+        assert!(
+            idx < self.len(),
+            "cannot remove a char at byte index {} from the end of a string of length {}",
+            idx,
+            self.len()
+        );
+        self.check_char_boundary(idx);
         self.as_string(|s| s.remove(idx.into_usize()))
     }
 
@@ -167,7 +453,7 @@ impl String32 {
     ///
     /// # Panics
     ///
-    /// Panics if `idx` is not a UTF-8 code point boundary, or if the resulting string would require more than [`u32::MAX`] bytes.
+    /// Panics if `idx` is larger than the `String32`'s length, if it is not a UTF-8 code point boundary, or if the resulting string would require more than [`u32::MAX`] bytes.
     ///
     /// # Examples
     ///
@@ -179,6 +465,7 @@ impl String32 {
     /// assert_eq!(s, "abc");
     /// ```
     pub fn insert(&mut self, idx: u32, ch: char) {
+        self.check_char_boundary(idx);
         self.as_string(|s| s.insert(idx.into_usize(), ch));
     }
 
@@ -186,7 +473,7 @@ impl String32 {
     ///
     /// # Panics
     ///
-    /// Panics if `idx` is not a UTF-8 code point boundary, or if the resulting string would require more than [`u32::MAX`] bytes.
+    /// Panics if `idx` is larger than the `String32`'s length, if it is not a UTF-8 code point boundary, or if the resulting string would require more than [`u32::MAX`] bytes.
     ///
     /// # Examples
     ///
@@ -201,9 +488,185 @@ impl String32 {
     where
         S: AsRef<str>,
     {
+        self.check_char_boundary(idx);
         self.as_string(|st| st.insert_str(idx.into_usize(), s.as_ref()));
     }
 
+    /// Insert a string slice at the given byte index, returning the new length in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not a UTF-8 code point boundary, or if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("ad").unwrap();
+    /// assert_eq!(4, s.insert_str_at(1, "bc"));
+    /// assert_eq!(4, s.len());
+    /// ```
+    pub fn insert_str_at(&mut self, idx: u32, s: &str) -> u32 {
+        self.insert_str(idx, s);
+        self.len()
+    }
+
+    /// Append formatted text onto the end of this `String32`, after reserving space for at least `hint` more bytes.
+    ///
+    /// Reserving up front avoids repeated reallocation when the formatted output is large. See also the [`write32!`](crate::write32) macro.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if a formatting trait implementation returns an error, or if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if reserving `hint` more bytes would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::new();
+    /// s.write_fmt_reserved(16, format_args!("{}-{}", 1, 2)).unwrap();
+    /// assert_eq!("1-2", s);
+    /// assert!(s.capacity() >= 16);
+    /// ```
+    pub fn write_fmt_reserved(&mut self, hint: u32, args: fmt::Arguments<'_>) -> fmt::Result {
+        self.reserve(hint);
+        fmt::Write::write_fmt(self, args)
+    }
+
+    /// Append a string slice to the end of this `String32`, returning the new length in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("ab").unwrap();
+    /// assert_eq!(4, s.push_str_len("cd"));
+    /// assert_eq!(4, s.len());
+    /// ```
+    pub fn push_str_len(&mut self, s: &str) -> u32 {
+        self.push_str(s);
+        self.len()
+    }
+
+    /// Insert a string slice at the start of this `String32`.
+    ///
+    /// This moves the existing contents, so building a string by repeatedly prepending is quadratic.
+    /// Consider using a [`PrependBuilder`](crate::PrependBuilder) instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("c").unwrap();
+    /// s.prepend_str("a/b/");
+    /// assert_eq!(s, "a/b/c");
+    /// ```
+    pub fn prepend_str(&mut self, s: &str) {
+        self.insert_str(0, s);
+    }
+
+    /// Insert a string slice at the given byte index, without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EditError::OutOfBounds`] if `idx` is larger than the `String32`'s length, [`EditError::NotCharBoundary`] if `idx` is not a UTF-8 code point boundary, or [`EditError::TooLarge`] if the resulting string would require more than [`u32::MAX`] bytes.
+    /// The `String32` is left unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::{EditError, String32};
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("aé").unwrap();
+    /// assert_eq!(Ok(()), s.try_insert_str(1, "b"));
+    /// assert_eq!("abé", s);
+    /// assert_eq!(Err(EditError::NotCharBoundary), s.try_insert_str(3, "c"));
+    /// assert_eq!(Err(EditError::OutOfBounds), s.try_insert_str(9, "c"));
+    /// assert_eq!("abé", s);
+    /// ```
+    pub fn try_insert_str(&mut self, idx: u32, s: &str) -> Result<(), EditError> {
+        if idx > self.len() {
+            return Err(EditError::OutOfBounds);
+        }
+        if !self.is_char_boundary(idx) {
+            return Err(EditError::NotCharBoundary);
+        }
+        u32::try_from(s.len())
+            .ok()
+            .and_then(|n| self.len().checked_add(n))
+            .ok_or(EditError::TooLarge)?;
+        self.insert_str(idx, s);
+        Ok(())
+    }
+
+    /// Retains only the `char`s for which `f` returns `true`, given each `char` and its `char` index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("abcd").unwrap();
+    /// s.retain_indexed(|i, _| i % 2 == 0);
+    /// assert_eq!("ac", s);
+    /// ```
+    pub fn retain_indexed<F>(&mut self, mut f: F)
+    where
+        F: FnMut(u32, char) -> bool,
+    {
+        let mut idx = 0;
+        self.as_string(|s| {
+            s.retain(|ch| {
+                let keep = f(idx, ch);
+                idx += 1;
+                keep
+            })
+        });
+    }
+
+    /// Retains only the `char`s for which `f` returns `true`, returning the number of `char`s removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("a1!b2?").unwrap();
+    /// assert_eq!(2, s.retain_count(char::is_alphanumeric));
+    /// assert_eq!("a1b2", s);
+    /// ```
+    pub fn retain_count<F>(&mut self, mut f: F) -> u32
+    where
+        F: FnMut(char) -> bool,
+    {
+        let mut removed = 0;
+        self.as_string(|s| {
+            s.retain(|ch| {
+                let keep = f(ch);
+                if !keep {
+                    removed += 1;
+                }
+                keep
+            })
+        });
+        removed
+    }
+
     /// Reserve space for additional bytes.
     ///
     /// # Examples
@@ -222,34 +685,223 @@ impl String32 {
 
     /// Reserve space for an exact number of bytes.
     ///
+    /// Unlike [`String32::reserve`], this does not over-allocate to amortize future growth: if the current capacity is insufficient, the new capacity is exactly `len + additional`.
+    ///
     /// # Examples
     ///
     /// ```
     /// # use string32::String32;
     /// let mut s = String32::with_capacity(5);
     /// s.reserve_exact(10);
-    /// assert!(s.capacity() >= 10);
+    /// assert_eq!(s.capacity(), 10);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: u32) {
+        self.0.reserve_exact(additional)
+    }
+
+    /// Collapses each run of consecutive `ch`s into a single `ch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("a///b//c").unwrap();
+    /// s.dedup_char('/');
+    /// assert_eq!("a/b/c", s);
+    /// ```
+    pub fn dedup_char(&mut self, ch: char) {
+        self.dedup_by(|a, b| a == ch && b == ch);
+    }
+
+    /// Removes consecutive `char`s for which `same_run` returns `true`.
+    ///
+    /// `same_run` is passed each `char` and the last `char` that was kept before it, in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("aAbBBc").unwrap();
+    /// s.dedup_by(|a, b| a.eq_ignore_ascii_case(&b));
+    /// assert_eq!("abc", s);
+    /// ```
+    pub fn dedup_by<F>(&mut self, mut same_run: F)
+    where
+        F: FnMut(char, char) -> bool,
+    {
+        let mut prev = None;
+        self.as_string(|s| {
+            s.retain(|ch| {
+                let keep = !prev.is_some_and(|prev| same_run(ch, prev));
+                if keep {
+                    prev = Some(ch);
+                }
+                keep
+            })
+        });
+    }
+
+    /// Reserve space for enough additional bytes to hold `chars` more `char`s of any width.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity would exceed [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::new();
+    /// s.reserve_chars(10);
+    /// assert!(s.capacity() >= 40);
+    /// ```
+    pub fn reserve_chars(&mut self, chars: u32) {
+        self.reserve_chars_width(chars, 4);
+    }
+
+    /// Reserve space for enough additional bytes to hold `chars` more `char`s that are each at most `max_width` bytes long in UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity would exceed [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::new();
+    /// s.reserve_chars_width(10, 2);
+    /// assert!(s.capacity() >= 20);
+    /// ```
+    pub fn reserve_chars_width(&mut self, chars: u32, max_width: u32) {
+        self.reserve(
+            chars
+                .checked_mul(max_width)
+                .expect("string too large for u32-indexed buffer"),
+        );
+    }
+
+    /// Reserve space for additional bytes, returning whether the capacity changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::with_capacity(10);
+    /// assert!(!s.reserve_tracked(10));
+    /// assert!(s.reserve_tracked(11));
+    /// ```
+    pub fn reserve_tracked(&mut self, additional: u32) -> bool {
+        let cap = self.capacity();
+        self.reserve(additional);
+        cap != self.capacity()
+    }
+
+    /// Reserve space for additional bytes, growing the capacity geometrically.
+    ///
+    /// Unlike [`String32::reserve_exact`], this reserves at least as many bytes as the current capacity whenever it has to grow, so repeated small reservations only reallocate a logarithmic number of times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::with_capacity(8);
+    /// s.push_str("12345678");
+    /// s.reserve_amortized(1);
+    /// assert!(s.capacity() >= 16);
+    /// ```
+    pub fn reserve_amortized(&mut self, additional: u32) {
+        self.check_additional(additional.into_usize());
+        if additional > self.capacity() - self.len() {
+            let doubling = cmp::min(self.capacity(), u32::MAX - self.len());
+            self.reserve_exact(cmp::max(additional, doubling));
+        }
+    }
+
+    /// Shrink the capacity of this `String32` to match its length.
+    ///
+    /// This does nothing if the capacity already matches the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::with_capacity(10);
+    /// s.shrink_to_fit();
+    /// assert_eq!(0, s.capacity());
+    ///
+    /// s.push_str("abc");
+    /// s.shrink_to_fit();
+    /// let (cap, ptr) = (s.capacity(), s.as_ptr());
+    /// s.shrink_to_fit();
+    /// assert_eq!((cap, ptr), (s.capacity(), s.as_ptr()));
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity() != self.len() {
+            self.as_string(String::shrink_to_fit);
+        }
+    }
+
+    /// Shrink the capacity of this `String32` to match its length, but only if that would free at least `min_savings` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut s = String32::with_capacity(10);
+    /// s.push_str("123456789");
+    /// s.shrink_to_fit_threshold(16);
+    /// assert_eq!(10, s.capacity());
+    /// s.shrink_to_fit_threshold(1);
+    /// assert_eq!(9, s.capacity());
     /// ```
-    pub fn reserve_exact(&mut self, additional: u32) {
-        self.0.reserve_exact(additional)
+    pub fn shrink_to_fit_threshold(&mut self, min_savings: u32) {
+        if self.capacity() - self.len() >= min_savings {
+            self.shrink_to_fit();
+        }
     }
 
-    /// Shrink the capacity of this `String32` to match its length.
+    /// Shrink the capacity of this `String32` to the smallest power of two that can hold its length, and no less than 8 bytes.
+    ///
+    /// This keeps capacities pool-friendly, unlike [`String32::shrink_to_fit`]. It never grows the capacity, and does nothing if the length is larger than `2^31`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use string32::String32;
-    /// let mut s = String32::with_capacity(10);
-    /// s.shrink_to_fit();
-    /// assert_eq!(0, s.capacity());
+    /// let mut s = String32::with_capacity(1000);
+    /// s.push_str(&"x".repeat(70));
+    /// s.shrink_to_pow2();
+    /// assert_eq!(128, s.capacity());
+    ///
+    /// s.clear();
+    /// s.shrink_to_pow2();
+    /// assert_eq!(8, s.capacity());
     /// ```
-    pub fn shrink_to_fit(&mut self) {
-        self.as_string(String::shrink_to_fit);
+    pub fn shrink_to_pow2(&mut self) {
+        const MIN_CAP: u32 = 8;
+        if let Some(cap) = self.len().checked_next_power_of_two() {
+            let cap = cap.max(MIN_CAP);
+            if self.capacity() > cap {
+                self.as_string(|s| s.shrink_to(cap.into_usize()));
+            }
+        }
     }
 
     /// Shortens this `String32` to the specified length.
     ///
+    /// If `new_len` is greater than the string's current length, this has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on a UTF-8 code point boundary.
+    ///
     /// # Examples
     ///
     /// ```
@@ -260,9 +912,34 @@ impl String32 {
     /// assert_eq!(s, "abc");
     /// ```
     pub fn truncate(&mut self, new_len: u32) {
+        if new_len > self.len() {
+            return;
+        }
+        self.check_char_boundary(new_len);
         self.as_string(|s| s.truncate(new_len.into_usize()));
     }
 
+    /// Shortens this `String32` to at most `max_len` bytes, rounding down to the nearest UTF-8 code point boundary.
+    ///
+    /// Unlike [`String32::truncate`], this never splits a `char` and never panics, which makes it suitable for enforcing a byte budget on arbitrary text.
+    /// If `max_len` is greater than the string's current length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("áé").unwrap();
+    /// s.truncate_to_boundary(3);
+    /// assert_eq!("á", s);
+    /// s.truncate_to_boundary(5);
+    /// assert_eq!("á", s);
+    /// ```
+    pub fn truncate_to_boundary(&mut self, max_len: u32) {
+        let new_len = self.floor_char_boundary(max_len);
+        self.truncate(new_len);
+    }
+
     /// Truncates the `String32` into an empty string.
     ///
     /// # Examples
@@ -275,7 +952,33 @@ impl String32 {
     /// assert!(s.is_empty());
     /// ```
     pub fn clear(&mut self) {
-        self.0.clear()
+        self.0.as_vec(Vec::clear);
+    }
+
+    /// Overwrites the entire capacity of the `String32` with zero bytes, then truncates it into an empty string.
+    ///
+    /// The writes are volatile, so they are not optimized away even though the bytes are never read again. The capacity is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("hunter2").unwrap();
+    /// let cap = s.capacity();
+    /// s.zeroize();
+    /// assert!(s.is_empty());
+    /// assert_eq!(cap, s.capacity());
+    /// ```
+    pub fn zeroize(&mut self) {
+        self.0.as_vec(|v| {
+            let ptr = v.as_mut_ptr();
+            for i in 0..v.capacity() {
+                unsafe { ptr::write_volatile(ptr.add(i), 0) };
+            }
+            v.clear();
+        });
+        atomic::compiler_fence(atomic::Ordering::SeqCst);
     }
 
     /// Converts a `String32` into a vector of bytes.
@@ -294,6 +997,23 @@ impl String32 {
         self.0.into_vec()
     }
 
+    /// Converts a `String32` into an iterator over its `char`s, without borrowing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let s = String32::try_from("aé€").unwrap();
+    /// let v: String = s.clone().into_chars().collect();
+    /// assert_eq!(s, v);
+    /// let v: String = s.into_chars().rev().collect();
+    /// assert_eq!("€éa", v);
+    /// ```
+    pub fn into_chars(self) -> IntoChars {
+        IntoChars::new(self)
+    }
+
     /// Converts a `String32` into a [`Box<str>`].
     ///
     /// # Examples
@@ -309,6 +1029,22 @@ impl String32 {
         String::from(self).into_boxed_str()
     }
 
+    /// Converts a `String32` into a `Box<[u8]>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let s = String32::try_from("123").unwrap();
+    /// let b = s.into_boxed_bytes();
+    /// assert_eq!(b"123", &*b);
+    /// ```
+    #[must_use]
+    pub fn into_boxed_bytes(self) -> Box<[u8]> {
+        self.into()
+    }
+
     /// Splits the `String32` into two at the given byte index.
     ///
     /// # Panics
@@ -327,15 +1063,140 @@ impl String32 {
     /// ```
     #[must_use = "if you plan to discard the second half, consider using `String32::truncate` instead"]
     pub fn split_off(&mut self, at: u32) -> Self {
+        self.check_char_boundary(at);
         self.as_string(|s| s.split_off(at.into_usize()).try_into().unwrap())
     }
 
+    /// Splits the string into two at the given byte index, returning the front and keeping the rest.
+    ///
+    /// Returns a newly allocated `String32` containing the bytes `[0, at)`, while `self` keeps the bytes `[at, len)` in its existing buffer. This is convenient for parsers that consume input from the front.
+    ///
+    /// Note that this moves the remaining `len - at` bytes to the start of the buffer, which is an `O(len - at)` memmove.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out-of-bounds or is not a UTF-8 code point boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s1 = String32::try_from("123abc").unwrap();
+    /// let cap = s1.capacity();
+    /// let s2 = s1.split_off_front(3);
+    /// assert_eq!("123", s2);
+    /// assert_eq!("abc", s1);
+    /// assert_eq!(cap, s1.capacity());
+    /// ```
+    #[must_use]
+    pub fn split_off_front(&mut self, at: u32) -> Self {
+        self.check_char_boundary(at);
+        self.as_string(|s| {
+            let front = s[..at.into_usize()].try_into().unwrap();
+            s.drain(..at.into_usize());
+            front
+        })
+    }
+
+    /// Removes the given byte range from this `String32`, returning it as a new `String32`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start or end of the range is out-of-bounds or is not a UTF-8 code point boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("hello").unwrap();
+    /// assert_eq!("ell", s.remove_range(1..4));
+    /// assert_eq!("ho", s);
+    /// ```
+    pub fn remove_range<R>(&mut self, range: R) -> Self
+    where
+        R: RangeBounds<u32>,
+    {
+        let bounds = usize_bounds(&range);
+        self.as_string(|s| s.drain(bounds).collect::<String>().try_into().unwrap())
+    }
+
+    /// Removes the given byte range from this `String32`, returning the removed bytes.
+    ///
+    /// This is useful for handing the removed section to code that expects raw bytes, such as over FFI.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start or end of the range is out-of-bounds or is not a UTF-8 code point boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("hello").unwrap();
+    /// assert_eq!(b"el", &s.drain_bytes(1..3)[..]);
+    /// assert_eq!("hlo", s);
+    /// ```
+    pub fn drain_bytes<R>(&mut self, range: R) -> Vec<u8>
+    where
+        R: RangeBounds<u32>,
+    {
+        let bounds = usize_bounds(&range);
+        self.as_string(|s| s.drain(bounds).as_str().as_bytes().to_vec())
+    }
+
+    /// Replaces the given range of `char`s with a string slice.
+    ///
+    /// The range is given in `char` indices rather than byte indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start or end of the range is greater than the number of `char`s, if the start is greater than the end, or if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::convert::TryFrom;
+    /// let mut s = String32::try_from("áéí").unwrap();
+    /// s.replace_char_range(1..2, "XX");
+    /// assert_eq!("áXXí", s);
+    /// ```
+    pub fn replace_char_range(&mut self, char_range: ops::Range<u32>, replace_with: &str) {
+        let start = self
+            .byte_of_char(char_range.start)
+            .expect("char index out of bounds");
+        let end = self
+            .byte_of_char(char_range.end)
+            .expect("char index out of bounds");
+        self.as_string(|s| s.replace_range(start.into_usize()..end.into_usize(), replace_with));
+    }
+
     /// Create a new `String32` from a raw pointer and corresponding length/capacity.
     ///
     /// # Safety
     ///
-    /// See [`String::from_raw_parts`].
+    /// The same contract as [`String::from_raw_parts`] applies. In particular:
+    ///
+    /// * `buf` must have been allocated by the global allocator, with an alignment of 1 and a size of exactly `cap` bytes (e.g. by a `Vec<u8>`, `String`, or `String32`).
+    /// * `len` must be less than or equal to `cap`.
+    /// * The first `len` bytes at `buf` must be initialized and valid UTF-8.
+    ///
+    /// Ownership of the allocation is transferred to the returned `String32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let mut v = std::mem::ManuallyDrop::new(b"hello".to_vec());
+    /// let (ptr, len, cap) = (v.as_mut_ptr(), v.len() as u32, v.capacity() as u32);
+    /// let s = unsafe { String32::from_raw_parts(ptr, len, cap) };
+    /// assert_eq!("hello", s);
+    /// ```
     pub unsafe fn from_raw_parts(buf: *mut u8, len: u32, cap: u32) -> Self {
+        debug_assert!(len <= cap);
         Self(Vec32::from_vec(Vec::from_raw_parts(
             buf,
             len.into_usize(),
@@ -352,8 +1213,93 @@ impl String32 {
     /// # Panics
     ///
     /// Panics if the provided [`Vec<u8>`] holds more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let s = String32::from_utf8(b"abc".to_vec()).unwrap();
+    /// assert_eq!("abc", s);
+    ///
+    /// let err = String32::from_utf8(vec![b'a', 0xFF]).unwrap_err();
+    /// assert_eq!(vec![b'a', 0xFF], err.into_bytes());
+    /// ```
     pub fn from_utf8(v: Vec<u8>) -> Result<Self, string::FromUtf8Error> {
-        String::from_utf8(v).map(|s| s.try_into().unwrap())
+        assert!(
+            v.len() <= u32::MAX.into_usize(),
+            "string too large for u32-indexed buffer"
+        );
+        match std::str::from_utf8(&v) {
+            Ok(_) => Ok(Self(Vec32::from_vec(v))),
+            Err(_) => Err(String::from_utf8(v).unwrap_err()),
+        }
+    }
+
+    /// Converts a slice of bytes to a string, replacing invalid UTF-8 sequences with [`char::REPLACEMENT_CHARACTER`].
+    ///
+    /// If the slice is already valid UTF-8, it is borrowed as a [`Str32`] without allocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting string would hold more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// # use std::borrow::Cow;
+    /// let s = String32::from_utf8_lossy_cow(b"abc");
+    /// assert!(matches!(s, Cow::Borrowed(_)));
+    /// assert_eq!("abc", &*s);
+    ///
+    /// let s = String32::from_utf8_lossy_cow(&[b'a', 0xFF]);
+    /// assert!(matches!(s, Cow::Owned(_)));
+    /// assert_eq!("a\u{FFFD}", &*s);
+    /// ```
+    #[must_use]
+    pub fn from_utf8_lossy_cow(v: &[u8]) -> Cow<'_, Str32> {
+        match String::from_utf8_lossy(v) {
+            Cow::Borrowed(s) => Cow::Borrowed(s.try_into().unwrap()),
+            Cow::Owned(s) => Cow::Owned(s.try_into().unwrap()),
+        }
+    }
+
+    /// Collects an iterator of `char`s into a `String32`, returning an error instead of aborting or panicking if the buffer cannot be allocated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the allocator reports a failure, or if the resulting string would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let s = String32::try_from_iter("abc".chars()).unwrap();
+    /// assert_eq!("abc", s);
+    ///
+    /// let huge = std::iter::repeat('a').take(usize::MAX);
+    /// assert!(String32::try_from_iter(huge).is_err());
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, TryReserveError>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        if lower > u32::MAX.into_usize() {
+            return Err(capacity_overflow());
+        }
+        let mut s = String::new();
+        s.try_reserve(lower)?;
+        for ch in iter {
+            let n = ch.len_utf8();
+            if s.len() + n > u32::MAX.into_usize() {
+                return Err(capacity_overflow());
+            }
+            s.try_reserve(n)?;
+            s.push(ch);
+        }
+        Ok(Self(Vec32::from_vec(s.into_bytes())))
     }
 
     /// Decodes a UTF-16 encoded slice into a `String32`.
@@ -365,8 +1311,24 @@ impl String32 {
     /// # Panics
     ///
     /// Panics if the resulting UTF-8 representation would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let v = [0x0061, 0xD834, 0xDD1E, 0x0062];
+    /// assert_eq!("a𝄞b", String32::from_utf16(&v).unwrap());
+    /// assert!(String32::from_utf16(&[0xD834]).is_err());
+    /// ```
     pub fn from_utf16(v: &[u16]) -> Result<Self, string::FromUtf16Error> {
-        String::from_utf16(v).map(|s| s.try_into().unwrap())
+        let mut s = Self::with_capacity(v.len().try_into().unwrap_or(u32::MAX));
+        for ch in char::decode_utf16(v.iter().copied()) {
+            match ch {
+                Ok(ch) => s.push_utf8(ch),
+                Err(_) => return Err(String::from_utf16(v).unwrap_err()),
+            }
+        }
+        Ok(s)
     }
 
     /// Lossily decodes a UTF-16 encoded slice into a `String32`.
@@ -374,16 +1336,40 @@ impl String32 {
     /// # Panics
     ///
     /// Panics if the resulting UTF-8 representation would require more than [`u32::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use string32::String32;
+    /// let v = [0x0061, 0xD834, 0xDD1E, 0xD834];
+    /// assert_eq!("a𝄞\u{FFFD}", String32::from_utf16_lossy(&v));
+    /// ```
     #[must_use]
     pub fn from_utf16_lossy(v: &[u16]) -> Self {
-        String::from_utf16_lossy(v).try_into().unwrap()
+        let mut s = Self::with_capacity(v.len().try_into().unwrap_or(u32::MAX));
+        for ch in char::decode_utf16(v.iter().copied()) {
+            s.push_utf8(ch.unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+        s
     }
 }
 
+/// Return the error reported when a requested capacity is too large, since `TryReserveError` cannot be constructed directly.
+fn capacity_overflow() -> TryReserveError {
+    Vec::<u8>::new().try_reserve(usize::MAX).unwrap_err()
+}
+
+/// Concatenate owned strings into a single `String32`, allocating its buffer exactly once.
+fn concat<S: AsRef<str>>(pieces: &[S]) -> String32 {
+    let len = pieces.iter().map(|s| s.as_ref().len()).sum();
+    let mut ret = String::with_capacity(len);
+    ret.extend(pieces.iter().map(AsRef::as_ref));
+    ret.try_into().unwrap()
+}
+
 impl ops::Add<&str> for String32 {
     type Output = Self;
 
-    #[must_use]
     fn add(mut self, rhs: &str) -> Self {
         self.push_str(rhs);
         self
@@ -393,9 +1379,8 @@ impl ops::Add<&str> for String32 {
 impl ops::Add<&Str32> for String32 {
     type Output = Self;
 
-    #[must_use]
     fn add(mut self, rhs: &Str32) -> Self {
-        self.push_str(rhs);
+        self.push_str32(rhs);
         self
     }
 }
@@ -408,7 +1393,7 @@ impl ops::AddAssign<&str> for String32 {
 
 impl ops::AddAssign<&Str32> for String32 {
     fn add_assign(&mut self, rhs: &Str32) {
-        self.push_str(rhs);
+        self.push_str32(rhs);
     }
 }
 
@@ -448,6 +1433,13 @@ impl BorrowMut<Str32> for String32 {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for String32 {
+    fn zeroize(&mut self) {
+        Self::zeroize(self);
+    }
+}
+
 impl ops::Deref for String32 {
     type Target = Str32;
 
@@ -476,6 +1468,17 @@ impl fmt::Display for String32 {
     }
 }
 
+impl fmt::Write for String32 {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        u32::try_from(s.len())
+            .ok()
+            .and_then(|n| self.len().checked_add(n))
+            .ok_or(fmt::Error)?;
+        self.push_str(s);
+        Ok(())
+    }
+}
+
 impl From<&Str32> for String32 {
     fn from(s: &Str32) -> Self {
         s.to_owned()
@@ -502,9 +1505,49 @@ impl From<String32> for Vec<u8> {
     }
 }
 
+impl From<String32> for Box<Str32> {
+    fn from(s: String32) -> Self {
+        let ptr = Box::into_raw(s.into_boxed_str()) as *mut Str32;
+        unsafe {
+            // safety: relies on `Box<Str32>` and `Box<str>` having the same layout
+            Self::from_raw(ptr)
+        }
+    }
+}
+
+impl From<String32> for Arc<Str32> {
+    fn from(s: String32) -> Self {
+        let ptr = Arc::into_raw(Arc::<str>::from(s.as_str())) as *const Str32;
+        unsafe {
+            // safety: relies on `Arc<Str32>` and `Arc<str>` having the same layout
+            Self::from_raw(ptr)
+        }
+    }
+}
+
+impl From<String32> for Box<[u8]> {
+    fn from(s: String32) -> Self {
+        s.into_bytes().into_boxed_slice()
+    }
+}
+
 impl FromIterator<char> for String32 {
     fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
-        String::from_iter(iter).try_into().unwrap()
+        let iter = iter.into_iter();
+        let mut v = Vec::with_capacity(iter.size_hint().0);
+        for ch in iter {
+            if ch.is_ascii() {
+                v.push(ch as u8);
+            } else {
+                v.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+        }
+        debug_assert!(std::str::from_utf8(&v).is_ok());
+        assert!(
+            v.len() <= u32::MAX.into_usize(),
+            "string too large for u32-indexed buffer"
+        );
+        Self(Vec32::from_vec(v))
     }
 }
 
@@ -530,7 +1573,7 @@ impl<'a> FromIterator<&'a Str32> for String32 {
 
 impl FromIterator<Box<str>> for String32 {
     fn from_iter<I: IntoIterator<Item = Box<str>>>(iter: I) -> Self {
-        String::from_iter(iter).try_into().unwrap()
+        concat(&iter.into_iter().collect::<Vec<_>>())
     }
 }
 
@@ -544,15 +1587,18 @@ impl FromIterator<Box<Str32>> for String32 {
 
 impl FromIterator<String> for String32 {
     fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
-        String::from_iter(iter).try_into().unwrap()
+        concat(&iter.into_iter().collect::<Vec<_>>())
     }
 }
 
 impl FromIterator<Self> for String32 {
     fn from_iter<I: IntoIterator<Item = Self>>(iter: I) -> Self {
-        String::from_iter(iter.into_iter().map(String::from))
-            .try_into()
-            .unwrap()
+        let mut iter = iter.into_iter();
+        let mut ret = iter.next().unwrap_or_default();
+        for s in iter {
+            ret.push_str32(&s);
+        }
+        ret
     }
 }
 
@@ -617,6 +1663,13 @@ impl_cmp!(String32, Cow<'a, Str32>);
 impl_cmp!(String32, Cow<'a, str>);
 impl_cmp!(String32, Box<str>);
 impl_cmp!(String32, Box<Str32>);
+impl_cmp!(&'a String32, Str32);
+impl_cmp!(&'a String32, String);
+impl_cmp!(&'a String32, str);
+impl_cmp!(&'a String32, Cow<'b, Str32>);
+impl_cmp!(&'a String32, Cow<'b, str>);
+impl_cmp!(&'a String32, Box<str>);
+impl_cmp!(&'a String32, Box<Str32>);
 impl_cmp!(Str32, &'a Str32);
 impl_cmp!(Str32, String);
 impl_cmp!(Str32, str);
@@ -632,6 +1685,39 @@ impl_cmp!(&'a Str32, Cow<'b, str>);
 impl_cmp!(&'a Str32, Box<str>);
 impl_cmp!(&'a Str32, Box<Str32>);
 
+macro_rules! impl_cmp_bytes {
+    ($lhs:ty, $rhs: ty) => {
+        impl<'a> PartialEq<$rhs> for $lhs {
+            fn eq(&self, rhs: &$rhs) -> bool {
+                <[u8] as PartialEq>::eq(self.as_bytes(), &rhs[..])
+            }
+        }
+
+        impl<'a> PartialEq<$lhs> for $rhs {
+            fn eq(&self, rhs: &$lhs) -> bool {
+                <[u8] as PartialEq>::eq(&self[..], rhs.as_bytes())
+            }
+        }
+
+        impl<'a> PartialOrd<$rhs> for $lhs {
+            fn partial_cmp(&self, rhs: &$rhs) -> Option<cmp::Ordering> {
+                <[u8] as PartialOrd>::partial_cmp(self.as_bytes(), &rhs[..])
+            }
+        }
+
+        impl<'a> PartialOrd<$lhs> for $rhs {
+            fn partial_cmp(&self, rhs: &$lhs) -> Option<cmp::Ordering> {
+                <[u8] as PartialOrd>::partial_cmp(&self[..], rhs.as_bytes())
+            }
+        }
+    };
+}
+
+impl_cmp_bytes!(String32, Vec<u8>);
+impl_cmp_bytes!(String32, &'a Vec<u8>);
+impl_cmp_bytes!(Str32, Vec<u8>);
+impl_cmp_bytes!(Str32, &'a Vec<u8>);
+
 impl TryFrom<String> for String32 {
     type Error = TryFromStringError<String>;
 
@@ -654,6 +1740,33 @@ impl TryFrom<Box<str>> for String32 {
     }
 }
 
+impl TryFrom<Vec<char>> for String32 {
+    type Error = TryFromStringError<Vec<char>>;
+
+    fn try_from(v: Vec<char>) -> Result<Self, Self::Error> {
+        match Self::try_from(&v[..]) {
+            Ok(s) => Ok(s),
+            Err(_) => Err(TryFromStringError(v)),
+        }
+    }
+}
+
+impl TryFrom<&[char]> for String32 {
+    type Error = TryFromStrError;
+
+    fn try_from(v: &[char]) -> Result<Self, Self::Error> {
+        let len: usize = v.iter().map(|c| c.len_utf8()).sum();
+        match u32::try_from(len) {
+            Ok(_) => {
+                let mut s = String::with_capacity(len);
+                s.extend(v);
+                Ok(Self(Vec32::from_vec(s.into_bytes())))
+            }
+            Err(_) => Err(TryFromStrError(())),
+        }
+    }
+}
+
 impl TryFrom<&str> for String32 {
     type Error = TryFromStrError;
 
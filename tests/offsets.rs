@@ -0,0 +1,162 @@
+use std::convert::TryFrom;
+
+use string32::{Str32, String32};
+use usize_cast::IntoUsize;
+
+struct XorShift(u32);
+
+impl XorShift {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+
+    fn string(&mut self, len: u32) -> String32 {
+        (0..len)
+            .map(|_| match self.next() % 16 {
+                0 => 'é',
+                1 => '€',
+                2 => '𝄞',
+                3 => ' ',
+                4 => '\n',
+                5 => 'a',
+                n => char::from(b'a' + n as u8),
+            })
+            .collect()
+    }
+}
+
+fn strings() -> impl Iterator<Item = String32> {
+    let mut rng = XorShift(0x2545_F491);
+    (0..100)
+        .map(move |_| {
+            let len = rng.next() % 4096;
+            rng.string(len)
+        })
+        .chain(std::iter::once(XorShift(0x9E37_79B9).string(1 << 18)))
+}
+
+#[test]
+fn test_char_indices() {
+    for s in strings() {
+        let expected: Vec<_> = s.as_str().char_indices().collect();
+        let actual: Vec<_> = s.char_indices().map(|(i, c)| (i.into_usize(), c)).collect();
+        assert_eq!(expected, actual);
+
+        let expected: Vec<_> = s.as_str().char_indices().rev().collect();
+        let actual: Vec<_> = s
+            .char_indices()
+            .rev()
+            .map(|(i, c)| (i.into_usize(), c))
+            .collect();
+        assert_eq!(expected, actual);
+        assert_eq!(s.as_str().chars().count(), s.char_indices().count());
+    }
+}
+
+#[test]
+fn test_match_char_indices() {
+    for s in strings() {
+        let expected: Vec<_> = s
+            .as_str()
+            .chars()
+            .enumerate()
+            .filter(|&(_, c)| c == '€' || c == '\n')
+            .map(|(i, c)| (i, c.to_string()))
+            .collect();
+        let actual: Vec<_> = s
+            .match_char_indices(['€', '\n'])
+            .map(|(i, m)| (i.into_usize(), m.to_string()))
+            .collect();
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn test_split_indices() {
+    for s in strings() {
+        let expected: Vec<_> = s.as_str().split('\n').collect();
+        let mut actual = Vec::new();
+        for (i, part) in s.split_indices('\n') {
+            assert_eq!(
+                part,
+                &s.as_str()[i.into_usize()..][..part.len().into_usize()]
+            );
+            actual.push(part.as_str());
+        }
+        assert_eq!(expected, actual);
+
+        let pieces: Vec<_> = s.split_keep('\n').collect();
+        let rebuilt: String = pieces.iter().map(|(_, p)| p.as_str()).collect();
+        assert_eq!(s.as_str(), rebuilt);
+        assert!(pieces.iter().all(|&(m, p)| m == (p == "\n")));
+
+        let expected: Vec<_> = s.as_str().split_whitespace().collect();
+        let mut actual = Vec::new();
+        for (i, word) in s.split_whitespace_indices() {
+            assert_eq!(
+                word,
+                &s.as_str()[i.into_usize()..][..word.len().into_usize()]
+            );
+            actual.push(word.as_str());
+        }
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn test_find_byte() {
+    for s in strings() {
+        for b in [b' ', b'\n', b'a', 0xE2, 0xF0, b'z'] {
+            let expected = s.as_bytes().iter().position(|&x| x == b);
+            assert_eq!(expected, s.find_byte(b).map(IntoUsize::into_usize));
+            let expected = s.as_bytes().iter().rposition(|&x| x == b);
+            assert_eq!(expected, s.rfind_byte(b).map(IntoUsize::into_usize));
+            let expected = s.as_bytes().iter().filter(|&&x| x == b).count();
+            assert_eq!(expected, s.count_byte(b).into_usize());
+        }
+    }
+}
+
+#[test]
+fn test_char_boundaries() {
+    let mut rng = XorShift(0x1234_5678);
+    for _ in 0..100 {
+        let len = rng.next() % 256;
+        let s = rng.string(len);
+        for i in 0..=s.len() {
+            let floor = s.floor_char_boundary(i);
+            let ceil = s.ceil_char_boundary(i);
+            assert!(s.as_str().is_char_boundary(floor.into_usize()));
+            assert!(s.as_str().is_char_boundary(ceil.into_usize()));
+            assert!(floor <= i && i <= ceil);
+            if s.as_str().is_char_boundary(i.into_usize()) {
+                assert_eq!((i, i), (floor, ceil));
+                let char_idx = s.char_of_byte(i).unwrap();
+                assert_eq!(
+                    s.as_str()[..i.into_usize()].chars().count(),
+                    char_idx.into_usize()
+                );
+                assert_eq!(Some(i), s.byte_of_char(char_idx));
+                let (left, right) = s.split_at(i);
+                assert_eq!(
+                    s.as_str().split_at(i.into_usize()),
+                    (left.as_str(), right.as_str())
+                );
+            } else {
+                assert_eq!(None, s.char_of_byte(i));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_trim_span() {
+    for s in strings() {
+        let (start, end) = s.trim_span();
+        let sub = <&Str32>::try_from(&s.as_str()[start.into_usize()..end.into_usize()]).unwrap();
+        assert_eq!(s.as_str().trim(), sub);
+    }
+}